@@ -0,0 +1,31 @@
+//! Measures repeated conversions of the same unit pair through the factor cache of `convert`
+//! against resolving the factor for every value. Run with `cargo bench --bench convert`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use unit_conversion::{conversion_factor, convert};
+
+const ITERATIONS: u32 = 1_000_000;
+const PAIRS: [(&str, &str); 3] = [("bohr", "ang"), ("eV", "rcm"), ("psi", "GPa")];
+
+fn time(f: impl Fn(f64, &str, &str) -> f64) -> Duration {
+    let start = Instant::now();
+    for i in 0..ITERATIONS {
+        let (from, to) = PAIRS[i as usize % PAIRS.len()];
+        black_box(f(black_box(f64::from(i)), from, to));
+    }
+    start.elapsed()
+}
+
+fn main() {
+    let uncached = time(|v, from, to| v * conversion_factor(from, to).unwrap());
+    let cached = time(|v, from, to| convert(v, from, to).unwrap());
+    println!(
+        "{} conversions: resolved every time {:?}, cached {:?}, {:.1}x faster",
+        ITERATIONS,
+        uncached,
+        cached,
+        uncached.as_secs_f64() / cached.as_secs_f64()
+    );
+}
@@ -0,0 +1,84 @@
+//! Generates the const conversion table of the `const-table` feature from
+//! `src/const_table/factors.in`: every ordered pair of units of a category with the factor
+//! between them, sorted by the unit pair so it can be binary searched.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const SOURCE: &str = "src/const_table/factors.in";
+/// Size of the base unit, kept out of the generated expressions to not multiply by one.
+const ONE: &str = "1.0";
+
+fn main() {
+    // Printed before returning, as without any of them cargo reruns the script on every change
+    // in the package.
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", SOURCE);
+    // Only the `const-table` feature includes the table; other builds skip reading the source.
+    if env::var_os("CARGO_FEATURE_CONST_TABLE").is_none() {
+        return;
+    }
+    let source = fs::read_to_string(SOURCE).expect("the factor source is readable");
+
+    // Units with their size in the base unit and the size of the base unit in them, grouped by
+    // category.
+    let mut categories: Vec<Vec<(&str, String, String)>> = Vec::new();
+    for line in source.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with('[') && line.ends_with(']') {
+            categories.push(Vec::new());
+            continue;
+        }
+        let (unit, sizes) = line
+            .split_once('=')
+            .unwrap_or_else(|| panic!("line `{}` is not of the form `unit = size`", line));
+        let (size, inverse) = match sizes.split_once(';') {
+            Some((size, inverse)) => (size.trim().to_string(), inverse.trim().to_string()),
+            None if sizes.trim() == ONE => (ONE.to_string(), ONE.to_string()),
+            None => (
+                sizes.trim().to_string(),
+                format!("1.0 / ({})", sizes.trim()),
+            ),
+        };
+        categories
+            .last_mut()
+            .expect("units follow a `[category]` line")
+            .push((unit.trim(), size, inverse));
+    }
+
+    let mut pairs = Vec::new();
+    for units in &categories {
+        for (from, from_size, _) in units {
+            for (to, _, to_inverse) in units {
+                let factor = match (from_size.as_str(), to_inverse.as_str()) {
+                    (ONE, factor) | (factor, ONE) => factor.to_string(),
+                    _ => format!("({}) * ({})", from_size, to_inverse),
+                };
+                pairs.push((*from, *to, factor));
+            }
+        }
+    }
+    pairs.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+    if let Some(pair) = pairs
+        .windows(2)
+        .find(|w| (w[0].0, w[0].1) == (w[1].0, w[1].1))
+    {
+        panic!("the pair {} to {} is listed twice", pair[0].0, pair[0].1);
+    }
+
+    let mut table = format!(
+        "/// Every pair of units with the factor between them, sorted by the pair.\n\
+         pub(crate) static CONST_TABLE: [(&str, &str, f64); {}] = [\n",
+        pairs.len()
+    );
+    for (from, to, factor) in pairs {
+        table.push_str(&format!("    ({:?}, {:?}, {}),\n", from, to, factor));
+    }
+    table.push_str("];\n");
+
+    let out = Path::new(&env::var("OUT_DIR").expect("cargo sets OUT_DIR")).join("const_table.rs");
+    fs::write(out, table).expect("the generated table is writable");
+}
@@ -0,0 +1,46 @@
+//! This module offers acceleration unit conversion functions in double precision, i.e. f64.
+//! `g` is standard gravity here; as a plain unit `g` is the gram of the mass module, so it is an
+//! acceleration only when converted to or from another acceleration unit.
+
+use crate::table::conversion_table;
+
+/// Standard acceleration of gravity g₀ in m/s², exact by definition.
+const STANDARD_GRAVITY_TO_MPS2: f64 = 9.806_65;
+/// The gal of the CGS system, 1 cm/s², used in geophysics.
+const GAL_TO_MPS2: f64 = 1e-2;
+
+conversion_table!(
+    kind = "acceleration",
+    category = Acceleration,
+    a_kind = "an acceleration",
+    base = "metres per second squared",
+    /// Returns the size of the acceleration unit in metres per second squared.
+    factor = mps2_per,
+    to_base = to_mps2,
+    from_base = from_mps2,
+    to_map = convert_2_mps2_from,
+    from_map = convert_from_mps2_2,
+    units = {
+        "m/s2" => 1.0,
+        "g" => STANDARD_GRAVITY_TO_MPS2,
+        "Gal" => GAL_TO_MPS2,
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn standard_gravity_and_gal() {
+        assert_eq!(convert_2_mps2_from()["g"](1.0, "g"), Ok(9.806_65));
+        assert_relative_eq!(try_convert(1.0, "g", "Gal").unwrap(), 980.665);
+        assert_relative_eq!(
+            try_convert(try_convert(3.5, "m/s2", "Gal").unwrap(), "Gal", "m/s2").unwrap(),
+            3.5
+        );
+        assert_relative_eq!(crate::convert(1.0, "g", "m/s2").unwrap(), 9.806_65);
+    }
+}
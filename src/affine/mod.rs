@@ -0,0 +1,45 @@
+//! This module offers the affine conversions of units whose zero is offset from the zero of the
+//! base unit of their category, e.g. degree Celsius against kelvin or a gauge pressure against
+//! the absolute pressure.
+
+/// The conversion `base = scale * value + offset` of an affine unit to the base unit of its
+/// category, e.g. `scale = 1` and `offset = 273.15` for degree Celsius to kelvin.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AffineConversion {
+    /// Size of one unit in the base unit.
+    pub scale: f64,
+    /// Value of the zero of the unit in the base unit.
+    pub offset: f64,
+}
+
+impl AffineConversion {
+    pub const fn new(scale: f64, offset: f64) -> Self {
+        AffineConversion { scale, offset }
+    }
+
+    /// Converts `value` in the affine unit to the base unit.
+    pub fn to_base(self, value: f64) -> f64 {
+        value * self.scale + self.offset
+    }
+
+    /// Converts `value` in the base unit to the affine unit.
+    pub fn from_base(self, value: f64) -> f64 {
+        (value - self.offset) / self.scale
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn offset_and_scale_are_undone() {
+        let fahrenheit = AffineConversion::new(5.0 / 9.0, 273.15 - 32.0 * 5.0 / 9.0);
+        assert_relative_eq!(fahrenheit.to_base(32.0), 273.15);
+        assert_relative_eq!(fahrenheit.to_base(-40.0), 233.15);
+        assert_relative_eq!(fahrenheit.from_base(fahrenheit.to_base(98.6)), 98.6);
+    }
+}
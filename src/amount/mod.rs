@@ -0,0 +1,202 @@
+//! This module offers helpers to move between numbers of particles, amounts of substance and
+//! concentrations in double precision, i.e. f64.
+//! Concentrations are molar, i.e. given in mol per litre.
+
+use alloc::format;
+use alloc::string::ToString;
+
+use crate::{energy, ConversionError};
+
+/// Avogadro constant N_A in particles per mole (exact since the 2019 SI redefinition).
+pub(crate) const AVOGADRO_CONSTANT: f64 = 6.022_140_76e23;
+
+/// Energy units given per mole of particles.
+const MOLAR_ENERGY_UNITS: [&str; 2] = ["kJ/mol", "kcal/mol"];
+
+/// Function to convert a number of particles to moles.
+/// # Arguments
+///  * `n` - number of particles
+///
+pub fn particles_to_moles(n: f64) -> f64 {
+    n / AVOGADRO_CONSTANT
+}
+
+/// Function to convert moles to a number of particles.
+/// # Arguments
+///  * `moles` - amount of substance in mol
+///
+pub fn moles_to_particles(moles: f64) -> f64 {
+    moles * AVOGADRO_CONSTANT
+}
+
+/// Function to compute the molar concentration of an amount of substance in a volume.
+/// # Arguments
+///  * `moles` - amount of substance in mol
+///  * `litres` - volume of the solution in litres
+///
+pub fn molarity(moles: f64, litres: f64) -> f64 {
+    moles / litres
+}
+
+/// Function to compute the amount of substance contained in a volume of given molarity.
+/// # Arguments
+///  * `molarity` - concentration in mol per litre
+///  * `litres` - volume of the solution in litres
+///
+pub fn moles_from_molarity(molarity: f64, litres: f64) -> f64 {
+    molarity * litres
+}
+
+/// Function to compute the number of particles contained in a volume of given molarity.
+/// # Arguments
+///  * `molarity` - concentration in mol per litre
+///  * `litres` - volume of the solution in litres
+///
+pub fn particles_from_molarity(molarity: f64, litres: f64) -> f64 {
+    moles_to_particles(moles_from_molarity(molarity, litres))
+}
+
+/// The molar mass of a substance in g/mol, e.g. 18.015 g/mol for water.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct MolarMass(f64);
+
+impl MolarMass {
+    /// Creates a molar mass of `grams_per_mole` g/mol. Returns `InvalidQuantity` if it is not
+    /// positive.
+    pub fn new(grams_per_mole: f64) -> Result<Self, ConversionError> {
+        if grams_per_mole > 0.0 {
+            Ok(MolarMass(grams_per_mole))
+        } else {
+            Err(ConversionError::InvalidQuantity(format!(
+                "{} g/mol",
+                grams_per_mole
+            )))
+        }
+    }
+
+    /// Returns the molar mass in g/mol.
+    pub fn grams_per_mole(self) -> f64 {
+        self.0
+    }
+}
+
+/// Function to convert the mass of a sample to its amount of substance.
+/// # Arguments
+///  * `grams` - mass of the sample in g
+///  * `molar_mass` - molar mass of the substance
+///
+pub fn grams_to_moles(grams: f64, molar_mass: MolarMass) -> f64 {
+    grams / molar_mass.0
+}
+
+/// Function to convert an amount of substance to the mass of the sample.
+/// # Arguments
+///  * `moles` - amount of substance in mol
+///  * `molar_mass` - molar mass of the substance
+///
+pub fn moles_to_grams(moles: f64, molar_mass: MolarMass) -> f64 {
+    moles * molar_mass.0
+}
+
+/// Checks that `particle_unit` is an energy per particle and `molar_unit` an energy per mole.
+fn check_energy_units(particle_unit: &str, molar_unit: &str) -> Result<(), ConversionError> {
+    if MOLAR_ENERGY_UNITS.contains(&particle_unit) {
+        return Err(ConversionError::UnknownUnit(particle_unit.to_string()));
+    }
+    if !MOLAR_ENERGY_UNITS.contains(&molar_unit) {
+        return Err(ConversionError::UnknownUnit(molar_unit.to_string()));
+    }
+    Ok(())
+}
+
+/// Function to convert an energy per particle to the energy per mole of particles, e.g. eV to
+/// kJ/mol by E·e·N_A/1000.
+/// # Arguments
+///  * `value` - energy per particle
+///  * `particle_unit` - energy unit per particle, e.g. `eV`, `hartree` or `rcm`
+///  * `molar_unit` - `kJ/mol` or `kcal/mol`
+///
+pub fn per_particle_to_molar(
+    value: f64,
+    particle_unit: &str,
+    molar_unit: &str,
+) -> Result<f64, ConversionError> {
+    check_energy_units(particle_unit, molar_unit)?;
+    energy::try_convert(value, particle_unit, molar_unit)
+}
+
+/// Function to convert an energy per mole of particles to the energy per particle, the inverse
+/// of [`per_particle_to_molar`].
+/// # Arguments
+///  * `value` - energy per mole
+///  * `molar_unit` - `kJ/mol` or `kcal/mol`
+///  * `particle_unit` - energy unit per particle, e.g. `eV`, `hartree` or `rcm`
+///
+pub fn molar_to_per_particle(
+    value: f64,
+    molar_unit: &str,
+    particle_unit: &str,
+) -> Result<f64, ConversionError> {
+    check_energy_units(particle_unit, molar_unit)?;
+    energy::try_convert(value, molar_unit, particle_unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn avogadro_particles_are_one_mole() {
+        assert_eq!(particles_to_moles(6.022_140_76e23), 1.0);
+        assert_eq!(moles_to_particles(1.0), 6.022_140_76e23);
+        assert_relative_eq!(molarity(0.5, 0.25), 2.0);
+        assert_relative_eq!(particles_from_molarity(1e-3, 1.0), 6.022_140_76e20);
+    }
+
+    #[test]
+    fn weighed_water_sample() {
+        let water = MolarMass::new(18.015).unwrap();
+        assert_eq!(grams_to_moles(18.015, water), 1.0);
+        assert_eq!(moles_to_grams(2.0, water), 36.03);
+        assert_eq!(
+            MolarMass::new(0.0),
+            Err(ConversionError::InvalidQuantity("0 g/mol".to_string()))
+        );
+        assert!(MolarMass::new(-18.015).is_err());
+        assert!(MolarMass::new(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn molar_energies() {
+        assert_relative_eq!(
+            per_particle_to_molar(1.0, "eV", "kJ/mol").unwrap(),
+            96.485,
+            max_relative = 1e-5
+        );
+        assert_relative_eq!(
+            per_particle_to_molar(1.0, "hartree", "kcal/mol").unwrap(),
+            627.5,
+            max_relative = 1e-4
+        );
+        assert_relative_eq!(
+            per_particle_to_molar(1000.0, "rcm", "kJ/mol").unwrap(),
+            11.963,
+            max_relative = 1e-4
+        );
+        assert_relative_eq!(
+            molar_to_per_particle(96.485_332_12, "kJ/mol", "eV").unwrap(),
+            1.0,
+            max_relative = 1e-9
+        );
+        assert_eq!(
+            per_particle_to_molar(1.0, "eV", "kJ"),
+            Err(ConversionError::UnknownUnit("kJ".to_string()))
+        );
+        assert_eq!(
+            molar_to_per_particle(1.0, "kJ/mol", "kcal/mol"),
+            Err(ConversionError::UnknownUnit("kcal/mol".to_string()))
+        );
+    }
+}
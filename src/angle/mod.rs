@@ -0,0 +1,47 @@
+//! This module offers angle unit conversion functions in double precision, i.e. f64.
+//! Arcminutes and arcseconds are derived from the degree, so the three units chain exactly.
+
+use crate::table::conversion_table;
+
+const DEGREE_TO_RADIAN: f64 = core::f64::consts::PI / 180.0;
+const GRADIAN_TO_RADIAN: f64 = core::f64::consts::PI / 200.0;
+const ARCMINUTE_TO_RADIAN: f64 = DEGREE_TO_RADIAN / 60.0;
+const ARCSECOND_TO_RADIAN: f64 = ARCMINUTE_TO_RADIAN / 60.0;
+
+conversion_table!(
+    kind = "angle",
+    category = Angle,
+    a_kind = "an angle",
+    base = "radian",
+    /// Returns the size of the angle unit in radian.
+    factor = radians_per,
+    to_base = to_radian,
+    from_base = from_radian,
+    to_map = convert_2_rad_from,
+    from_map = convert_from_rad_2,
+    units = {
+        "rad" => 1.0,
+        "deg" => DEGREE_TO_RADIAN,
+        "grad" => GRADIAN_TO_RADIAN,
+        "arcmin" => ARCMINUTE_TO_RADIAN,
+        "arcsec" => ARCSECOND_TO_RADIAN,
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn degrees_and_subdivisions() {
+        assert_relative_eq!(
+            try_convert(180.0, "deg", "rad").unwrap(),
+            core::f64::consts::PI
+        );
+        assert_relative_eq!(try_convert(1.0, "deg", "arcsec").unwrap(), 3_600.0);
+        assert_relative_eq!(try_convert(1.0, "deg", "arcmin").unwrap(), 60.0);
+        assert_relative_eq!(try_convert(200.0, "grad", "deg").unwrap(), 180.0);
+    }
+}
@@ -0,0 +1,45 @@
+//! This module offers area unit conversion functions in double precision, i.e. f64.
+//! Every factor is the square of the corresponding factor of the length module.
+
+use crate::length::{ANGSTROEM, BOHR_RADIUS_TO_METRES, CENTI};
+use crate::table::conversion_table;
+
+const SQUARE_CENTIMETRES: f64 = CENTI * CENTI;
+const SQUARE_ANGSTROEM: f64 = ANGSTROEM * ANGSTROEM;
+const SQUARE_BOHR: f64 = BOHR_RADIUS_TO_METRES * BOHR_RADIUS_TO_METRES;
+
+conversion_table!(
+    kind = "area",
+    category = Area,
+    a_kind = "an area",
+    base = "square metres",
+    /// Returns the size of the area unit in square metres.
+    factor = square_metres_per,
+    to_base = to_square_metres,
+    from_base = from_square_metres,
+    to_map = convert_2_sq_metres_from,
+    from_map = convert_from_sq_metres_2,
+    units = {
+        "m²" => 1.0,
+        "cm²" => SQUARE_CENTIMETRES,
+        "ang²" => SQUARE_ANGSTROEM,
+        "bohr²" => SQUARE_BOHR,
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn factors_are_squared_length_factors() {
+        assert_relative_eq!(
+            try_convert(1.0, "bohr²", "m²").unwrap(),
+            BOHR_RADIUS_TO_METRES.powi(2)
+        );
+        assert_relative_eq!(try_convert(1.0, "ang²", "m²").unwrap(), ANGSTROEM.powi(2));
+        assert_relative_eq!(try_convert(1.0, "cm²", "ang²").unwrap(), 1e16);
+    }
+}
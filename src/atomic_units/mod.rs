@@ -0,0 +1,107 @@
+//! This module offers conversions between Hartree atomic units and SI in double precision, i.e.
+//! f64. The atomic units are built from the bohr radius, the hartree, the electron mass and the
+//! elementary charge, so the sizes are taken from the length, energy, mass, time and charge
+//! modules.
+
+use crate::charge::ELEMENTARY_CHARGE_TO_COULOMB;
+use crate::energy::{EV_JOULE_CONVERSION_FACTOR, HARTREE_EV_CONVERSION_FACTOR};
+use crate::length::BOHR_RADIUS_TO_METRES;
+use crate::mass::ELECTRON_MASS_TO_KILOGRAMS;
+use crate::time::ATOMIC_UNIT_OF_TIME_TO_SECONDS;
+
+/// Atomic unit of velocity a₀E_h/ħ in metres per second, derived from the bohr radius and the
+/// atomic unit of time so that the three stay consistent.
+pub(crate) const ATOMIC_UNIT_OF_VELOCITY_TO_MPS: f64 =
+    BOHR_RADIUS_TO_METRES / ATOMIC_UNIT_OF_TIME_TO_SECONDS;
+
+/// The physical quantities having an atomic unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Quantity {
+    /// Bohr radius, SI unit metre.
+    Length,
+    /// Hartree, SI unit joule.
+    Energy,
+    /// Electron mass, SI unit kilogram.
+    Mass,
+    /// ħ/E_h, SI unit second.
+    Time,
+    /// Elementary charge, SI unit coulomb.
+    Charge,
+    /// a₀E_h/ħ, SI unit metre per second.
+    Velocity,
+}
+
+impl Quantity {
+    /// Returns the size of the atomic unit of the quantity in its SI unit.
+    pub fn si_factor(self) -> f64 {
+        match self {
+            Quantity::Length => BOHR_RADIUS_TO_METRES,
+            Quantity::Energy => HARTREE_EV_CONVERSION_FACTOR * EV_JOULE_CONVERSION_FACTOR,
+            Quantity::Mass => ELECTRON_MASS_TO_KILOGRAMS,
+            Quantity::Time => ATOMIC_UNIT_OF_TIME_TO_SECONDS,
+            Quantity::Charge => ELEMENTARY_CHARGE_TO_COULOMB,
+            Quantity::Velocity => ATOMIC_UNIT_OF_VELOCITY_TO_MPS,
+        }
+    }
+}
+
+/// Function to convert a value in atomic units to SI.
+/// # Arguments
+///  * `value` - value in atomic units
+///  * `quantity` - the quantity the value describes, determining the SI unit of the result
+///
+pub fn to_si(value: f64, quantity: Quantity) -> f64 {
+    value * quantity.si_factor()
+}
+
+/// Function to convert a value in SI to atomic units.
+/// # Arguments
+///  * `value` - value in the SI unit of the quantity
+///  * `quantity` - the quantity the value describes
+///
+pub fn from_si(value: f64, quantity: Quantity) -> f64 {
+    value / quantity.si_factor()
+}
+
+/// Function to convert a velocity in atomic units, i.e. bohr per atomic unit of time, to metres
+/// per second.
+pub const fn au_velocity_to_mps(v: f64) -> f64 {
+    v * ATOMIC_UNIT_OF_VELOCITY_TO_MPS
+}
+
+/// Function to convert a velocity in metres per second to atomic units.
+pub const fn mps_to_au_velocity(v: f64) -> f64 {
+    v / ATOMIC_UNIT_OF_VELOCITY_TO_MPS
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn velocity_is_bohr_per_time() {
+        assert_relative_eq!(
+            to_si(1.0, Quantity::Velocity),
+            to_si(1.0, Quantity::Length) / to_si(1.0, Quantity::Time),
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(
+            to_si(1.0, Quantity::Energy),
+            4.359_744_722e-18,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(from_si(to_si(3.5, Quantity::Mass), Quantity::Mass), 3.5);
+    }
+
+    #[test]
+    fn derived_velocity_matches_codata() {
+        assert_relative_eq!(
+            au_velocity_to_mps(1.0),
+            2.187_691_263e6,
+            max_relative = 1e-3
+        );
+        assert_relative_eq!(mps_to_au_velocity(au_velocity_to_mps(0.3)), 0.3);
+    }
+}
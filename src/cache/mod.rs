@@ -0,0 +1,57 @@
+//! This module offers the cache of the conversion factors resolved by [`crate::convert`].
+//!
+//! Resolving a conversion searches the categories for the units and does two map lookups, while
+//! a cached factor is a single lookup of the unit pair. `cargo bench --bench convert` measures
+//! repeated conversions of the same pair, which the cache speeds up about threefold; see
+//! `benches/convert.rs`. Only factors of proportional conversions are stored, affine and
+//! reciprocal conversions are not cached. Without the `std` feature there is no mutex to guard
+//! the cache, so nothing is cached.
+
+#[cfg(feature = "std")]
+pub(crate) use mutex::{cached_factor, store_factor};
+
+#[cfg(not(feature = "std"))]
+pub(crate) use uncached::{cached_factor, store_factor};
+
+#[cfg(feature = "std")]
+mod mutex {
+    use std::string::{String, ToString};
+    use std::sync::{Mutex, PoisonError};
+
+    use crate::collections::Map;
+    use crate::sync::OnceLock;
+
+    /// Factors by unit converted from and unit converted to. Nesting the maps lets a lookup
+    /// borrow the unit names instead of allocating a key.
+    type FactorCache = Mutex<Map<String, Map<String, f64>>>;
+
+    fn cache() -> &'static FactorCache {
+        static CACHE: OnceLock<FactorCache> = OnceLock::new();
+        CACHE.get_or_init(|| Mutex::new(Map::new()))
+    }
+
+    /// Returns the cached factor from `from` to `to`, if any.
+    pub(crate) fn cached_factor(from: &str, to: &str) -> Option<f64> {
+        let cache = cache().lock().unwrap_or_else(PoisonError::into_inner);
+        cache.get(from)?.get(to).copied()
+    }
+
+    /// Caches the factor from `from` to `to`.
+    pub(crate) fn store_factor(from: &str, to: &str, factor: f64) {
+        cache()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(from.to_string())
+            .or_default()
+            .insert(to.to_string(), factor);
+    }
+}
+
+#[cfg(not(feature = "std"))]
+mod uncached {
+    pub(crate) fn cached_factor(_from: &str, _to: &str) -> Option<f64> {
+        None
+    }
+
+    pub(crate) fn store_factor(_from: &str, _to: &str, _factor: f64) {}
+}
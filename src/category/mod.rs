@@ -0,0 +1,462 @@
+//! This module offers the categories, i.e. physical dimensions, the units are grouped in.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::affine::AffineConversion;
+use crate::collections::Map;
+use crate::{
+    acceleration, angle, area, charge, data, dipole, electric_field, energy, force, frequency,
+    length, magnetic_field, magnetic_moment, mass, momentum, power, pressure, solid_angle, speed,
+    temperature, time, viscosity, ConversionError, Dimension,
+};
+
+/// Conversion function of a unit, the value type of the conversion maps.
+///
+/// The prefix argument receives the unit key the callback is registered under, e.g. `kJ`, so one
+/// function can serve all SI-prefixed variants of a unit. Callbacks of unprefixed units ignore
+/// it; an empty prefix selects the unprefixed unit.
+pub type Callback = fn(f64, prefix: &str) -> Result<f64, ConversionError>;
+
+/// A category converting its units through its base unit, e.g. [`crate::length::Length`] through
+/// metres. Every category module names a marker type implementing it after the category.
+pub trait UnitCategory {
+    /// Converts `value` in `unit` to the base unit. Returns `UnknownUnit` if the category does
+    /// not know `unit`.
+    fn to_base(value: f64, unit: &str) -> Result<f64, ConversionError>;
+
+    /// Converts `value` in the base unit to `unit`. Returns `UnknownUnit` if the category does
+    /// not know `unit`.
+    fn from_base(value: f64, unit: &str) -> Result<f64, ConversionError>;
+}
+
+/// Converts `value` from the unit `from` to the unit `to` of the category `C` by going through
+/// its base unit, e.g. `convert_within::<Energy>(1.0, "hartree", "eV")`.
+pub fn convert_within<C: UnitCategory>(
+    value: f64,
+    from: &str,
+    to: &str,
+) -> Result<f64, ConversionError> {
+    C::from_base(C::to_base(value, from)?, to)
+}
+
+/// A group of units that can be converted into each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Category {
+    Length,
+    Energy,
+    Temperature,
+    Mass,
+    Time,
+    Pressure,
+    Frequency,
+    Force,
+    Power,
+    Charge,
+    Angle,
+    Area,
+    Speed,
+    Dipole,
+    MagneticField,
+    MagneticMoment,
+    Data,
+    Acceleration,
+    SolidAngle,
+    Momentum,
+    Viscosity,
+    ElectricField,
+}
+
+impl Category {
+    /// All categories, in the order they are tried when classifying a unit.
+    pub(crate) const ALL: [Category; 22] = [
+        Category::Length,
+        Category::Energy,
+        Category::Temperature,
+        Category::Mass,
+        Category::Time,
+        Category::Pressure,
+        Category::Frequency,
+        Category::Force,
+        Category::Power,
+        Category::Charge,
+        Category::Angle,
+        Category::Area,
+        Category::Speed,
+        Category::Dipole,
+        Category::MagneticField,
+        Category::MagneticMoment,
+        Category::Data,
+        Category::Acceleration,
+        Category::SolidAngle,
+        Category::Momentum,
+        Category::Viscosity,
+        Category::ElectricField,
+    ];
+
+    /// Returns the first category knowing `unit`, if any.
+    pub fn of(unit: &str) -> Option<Category> {
+        Category::ALL.into_iter().find(|c| c.contains(unit))
+    }
+
+    /// Returns whether `unit` is registered in the conversion maps of this category.
+    pub fn contains(self, unit: &str) -> bool {
+        self.to_base_map().contains_key(unit)
+    }
+
+    /// Returns the map converting the units of this category to the base unit of the category.
+    pub(crate) fn to_base_map(self) -> &'static Map<&'static str, Callback> {
+        match self {
+            Category::Length => length::convert_to_metres_from(),
+            Category::Energy => energy::convert_2_ev_from(),
+            Category::Temperature => temperature::convert_2_kelvin_from(),
+            Category::Mass => mass::convert_2_kg_from(),
+            Category::Time => time::convert_2_s_from(),
+            Category::Pressure => pressure::convert_2_pa_from(),
+            Category::Frequency => frequency::convert_2_hz_from(),
+            Category::Force => force::convert_2_newton_from(),
+            Category::Power => power::convert_2_watt_from(),
+            Category::Charge => charge::convert_2_coulomb_from(),
+            Category::Angle => angle::convert_2_rad_from(),
+            Category::Area => area::convert_2_sq_metres_from(),
+            Category::Speed => speed::convert_2_mps_from(),
+            Category::Dipole => dipole::convert_2_cm_from(),
+            Category::MagneticField => magnetic_field::convert_2_tesla_from(),
+            Category::MagneticMoment => magnetic_moment::convert_2_jpt_from(),
+            Category::Data => data::convert_2_bit_from(),
+            Category::Acceleration => acceleration::convert_2_mps2_from(),
+            Category::SolidAngle => solid_angle::convert_2_sr_from(),
+            Category::Momentum => momentum::convert_2_kgmps_from(),
+            Category::Viscosity => viscosity::convert_2_pas_from(),
+            Category::ElectricField => electric_field::convert_2_vpm_from(),
+        }
+    }
+
+    /// Returns the map converting the base unit of the category to the units of this category.
+    pub(crate) fn to_unit_map(self) -> &'static Map<&'static str, Callback> {
+        match self {
+            Category::Length => length::convert_metres_to(),
+            Category::Energy => energy::convert_from_ev_2(),
+            Category::Temperature => temperature::convert_from_kelvin_2(),
+            Category::Mass => mass::convert_from_kg_2(),
+            Category::Time => time::convert_from_s_2(),
+            Category::Pressure => pressure::convert_from_pa_2(),
+            Category::Frequency => frequency::convert_from_hz_2(),
+            Category::Force => force::convert_from_newton_2(),
+            Category::Power => power::convert_from_watt_2(),
+            Category::Charge => charge::convert_from_coulomb_2(),
+            Category::Angle => angle::convert_from_rad_2(),
+            Category::Area => area::convert_from_sq_metres_2(),
+            Category::Speed => speed::convert_from_mps_2(),
+            Category::Dipole => dipole::convert_from_cm_2(),
+            Category::MagneticField => magnetic_field::convert_from_tesla_2(),
+            Category::MagneticMoment => magnetic_moment::convert_from_jpt_2(),
+            Category::Data => data::convert_from_bit_2(),
+            Category::Acceleration => acceleration::convert_from_mps2_2(),
+            Category::SolidAngle => solid_angle::convert_from_sr_2(),
+            Category::Momentum => momentum::convert_from_kgmps_2(),
+            Category::Viscosity => viscosity::convert_from_pas_2(),
+            Category::ElectricField => electric_field::convert_from_vpm_2(),
+        }
+    }
+
+    /// Returns the key of the base unit of this category, which all conversions go through.
+    pub fn base_unit(self) -> &'static str {
+        match self {
+            Category::Length => "m",
+            Category::Energy => "eV",
+            Category::Temperature => "K",
+            Category::Mass => "kg",
+            Category::Time => "s",
+            Category::Pressure => "Pa",
+            Category::Frequency => "Hz",
+            Category::Force => "N",
+            Category::Power => "W",
+            Category::Charge => "C",
+            Category::Angle => "rad",
+            Category::Area => "m²",
+            Category::Speed => "m/s",
+            Category::Dipole => "Cm",
+            Category::MagneticField => "T",
+            Category::MagneticMoment => "J/T",
+            Category::Data => "bit",
+            Category::Acceleration => "m/s2",
+            Category::SolidAngle => "sr",
+            Category::Momentum => "kg*m/s",
+            Category::Viscosity => "Pa.s",
+            Category::ElectricField => "V/m",
+        }
+    }
+
+    /// Returns the dimension of the quantities of this category.
+    pub const fn dimension(self) -> Dimension {
+        match self {
+            Category::Length => Dimension::new(1, 0, 0, 0, 0, 0, 0),
+            Category::Energy => Dimension::new(2, 1, -2, 0, 0, 0, 0),
+            Category::Temperature => Dimension::new(0, 0, 0, 0, 1, 0, 0),
+            Category::Mass => Dimension::new(0, 1, 0, 0, 0, 0, 0),
+            Category::Time => Dimension::new(0, 0, 1, 0, 0, 0, 0),
+            Category::Pressure => Dimension::new(-1, 1, -2, 0, 0, 0, 0),
+            Category::Frequency => Dimension::new(0, 0, -1, 0, 0, 0, 0),
+            Category::Force => Dimension::new(1, 1, -2, 0, 0, 0, 0),
+            Category::Power => Dimension::new(2, 1, -3, 0, 0, 0, 0),
+            Category::Charge => Dimension::new(0, 0, 1, 1, 0, 0, 0),
+            Category::Angle => Dimension::DIMENSIONLESS,
+            Category::Area => Dimension::new(2, 0, 0, 0, 0, 0, 0),
+            Category::Speed => Dimension::new(1, 0, -1, 0, 0, 0, 0),
+            Category::Dipole => Dimension::new(1, 0, 1, 1, 0, 0, 0),
+            Category::MagneticField => Dimension::new(0, 1, -2, -1, 0, 0, 0),
+            Category::MagneticMoment => Dimension::new(2, 0, 0, 1, 0, 0, 0),
+            Category::Data => Dimension::DIMENSIONLESS,
+            Category::Acceleration => Dimension::new(1, 0, -2, 0, 0, 0, 0),
+            Category::SolidAngle => Dimension::DIMENSIONLESS,
+            Category::Momentum => Dimension::new(1, 1, -1, 0, 0, 0, 0),
+            Category::Viscosity => Dimension::new(-1, 1, -1, 0, 0, 0, 0),
+            Category::ElectricField => Dimension::new(1, 1, -3, -1, 0, 0, 0),
+        }
+    }
+
+    /// Returns whether `unit` is an affine unit of this category, i.e. one with an offset zero.
+    pub(crate) fn is_affine(self, unit: &str) -> bool {
+        self.affine_conversion(unit).is_some()
+    }
+
+    /// Returns the conversion of `unit` to the base unit of this category if `unit` is affine.
+    pub(crate) fn affine_conversion(self, unit: &str) -> Option<AffineConversion> {
+        match self {
+            Category::Temperature => temperature::affine_units().get(unit).copied(),
+            Category::Pressure => pressure::affine_units().get(unit).copied(),
+            _ => None,
+        }
+    }
+
+    /// Returns whether `unit` is inversely proportional to the base unit of this category.
+    pub(crate) fn is_reciprocal(self, unit: &str) -> bool {
+        match self {
+            Category::Energy => energy::RECIPROCAL_UNITS.contains(&unit),
+            _ => false,
+        }
+    }
+
+    /// Converts `value` between two units of this category.
+    pub(crate) fn try_convert(
+        self,
+        value: f64,
+        from: &str,
+        to: &str,
+    ) -> Result<f64, ConversionError> {
+        match self {
+            Category::Length => length::try_convert(value, from, to),
+            Category::Energy => energy::try_convert(value, from, to),
+            Category::Temperature => temperature::try_convert(value, from, to),
+            Category::Mass => mass::try_convert(value, from, to),
+            Category::Time => time::try_convert(value, from, to),
+            Category::Pressure => pressure::try_convert(value, from, to),
+            Category::Frequency => frequency::try_convert(value, from, to),
+            Category::Force => force::try_convert(value, from, to),
+            Category::Power => power::try_convert(value, from, to),
+            Category::Charge => charge::try_convert(value, from, to),
+            Category::Angle => angle::try_convert(value, from, to),
+            Category::Area => area::try_convert(value, from, to),
+            Category::Speed => speed::try_convert(value, from, to),
+            Category::Dipole => dipole::try_convert(value, from, to),
+            Category::MagneticField => magnetic_field::try_convert(value, from, to),
+            Category::MagneticMoment => magnetic_moment::try_convert(value, from, to),
+            Category::Data => data::try_convert(value, from, to),
+            Category::Acceleration => acceleration::try_convert(value, from, to),
+            Category::SolidAngle => solid_angle::try_convert(value, from, to),
+            Category::Momentum => momentum::try_convert(value, from, to),
+            Category::Viscosity => viscosity::try_convert(value, from, to),
+            Category::ElectricField => electric_field::try_convert(value, from, to),
+        }
+    }
+}
+
+/// Returns all categories, in the order they are tried when classifying a unit.
+pub fn categories() -> Vec<Category> {
+    Category::ALL.to_vec()
+}
+
+/// Returns the units registered for `category`, sorted alphabetically. The list is read from the
+/// conversion maps, so every registered alias, e.g. `J` and `joule`, shows up.
+pub fn supported_units(category: Category) -> Vec<&'static str> {
+    let mut units: Vec<&'static str> = category.to_base_map().keys().copied().collect();
+    units.sort_unstable();
+    units
+}
+
+/// Returns the units registered for `category` with the callbacks converting them to the base
+/// unit of the category, in no particular order. The unit key has to be passed as prefix to
+/// the callback.
+pub fn entries(category: Category) -> impl Iterator<Item = (&'static str, Callback)> {
+    category
+        .to_base_map()
+        .iter()
+        .map(|(&unit, &callback)| (unit, callback))
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Category::Length => write!(f, "length"),
+            Category::Energy => write!(f, "energy"),
+            Category::Temperature => write!(f, "temperature"),
+            Category::Mass => write!(f, "mass"),
+            Category::Time => write!(f, "time"),
+            Category::Pressure => write!(f, "pressure"),
+            Category::Frequency => write!(f, "frequency"),
+            Category::Force => write!(f, "force"),
+            Category::Power => write!(f, "power"),
+            Category::Charge => write!(f, "charge"),
+            Category::Angle => write!(f, "angle"),
+            Category::Area => write!(f, "area"),
+            Category::Speed => write!(f, "speed"),
+            Category::Dipole => write!(f, "dipole moment"),
+            Category::MagneticField => write!(f, "magnetic field"),
+            Category::MagneticMoment => write!(f, "magnetic moment"),
+            Category::Data => write!(f, "data size"),
+            Category::Acceleration => write!(f, "acceleration"),
+            Category::SolidAngle => write!(f, "solid angle"),
+            Category::Momentum => write!(f, "momentum"),
+            Category::Viscosity => write!(f, "viscosity"),
+            Category::ElectricField => write!(f, "electric field"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    fn round_trip<C: UnitCategory>(value: f64, from: &str, to: &str) -> f64 {
+        let there = convert_within::<C>(value, from, to).unwrap();
+        convert_within::<C>(there, to, from).unwrap()
+    }
+
+    #[test]
+    fn converts_generically_within_a_category() {
+        use crate::energy::Energy;
+        use crate::length::Length;
+
+        assert_eq!(
+            convert_within::<Length>(1.0, "bohr", "ang"),
+            length::try_convert(1.0, "bohr", "ang")
+        );
+        assert_eq!(
+            convert_within::<Energy>(1.0, "hartree", "rcm"),
+            energy::try_convert(1.0, "hartree", "rcm")
+        );
+        assert_eq!(Length::to_base(2.0, "cm"), Ok(0.02));
+        assert_eq!(Energy::from_base(2.5, "eV"), Ok(2.5));
+        assert_relative_eq!(
+            round_trip::<Length>(3.0, "mi", "nm"),
+            3.0,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            round_trip::<Energy>(3.0, "hartree", "kcal/mol"),
+            3.0,
+            max_relative = 1e-12
+        );
+        assert_eq!(
+            convert_within::<Length>(1.0, "eV", "m"),
+            Err(ConversionError::UnknownUnit("eV".to_string()))
+        );
+    }
+
+    #[test]
+    fn iterates_the_conversion_map_entries() {
+        let (key, identity) = entries(Category::Energy)
+            .find(|&(unit, _)| unit == "eV")
+            .unwrap();
+        assert_eq!(identity(2.5, key), Ok(2.5));
+        assert_eq!(
+            entries(Category::Energy).count(),
+            supported_units(Category::Energy).len()
+        );
+        for (unit, callback) in entries(Category::Length) {
+            assert!(callback(1.0, unit).unwrap() > 0.0, "{}", unit);
+        }
+    }
+
+    #[test]
+    fn lists_categories_and_units() {
+        let all = categories();
+        assert_eq!(all.first(), Some(&Category::Length));
+        assert!(all.contains(&Category::Temperature));
+        assert_eq!(
+            supported_units(Category::Temperature),
+            vec!["K", "degC", "degF", "degR"]
+        );
+        for category in all {
+            let units = supported_units(category);
+            assert!(!units.is_empty());
+            assert!(units.iter().all(|unit| category.contains(unit)));
+            let base = category.base_unit();
+            assert_eq!(category.to_base_map()[base](2.5, base), Ok(2.5), "{}", base);
+        }
+    }
+
+    /// Round trips every unit of every category through the base unit, on random values.
+    #[cfg(feature = "std")]
+    mod round_trips {
+        use alloc::vec::Vec;
+        use proptest::prelude::*;
+
+        use super::*;
+
+        /// Relative tolerance of a round trip. The energy tables tabulate both directions
+        /// separately, so their factors are inverse only to about 1e-10.
+        const TOLERANCE: f64 = 1e-8;
+
+        fn all_units() -> Vec<(Category, &'static str)> {
+            categories()
+                .into_iter()
+                .flat_map(|c| supported_units(c).into_iter().map(move |u| (c, u)))
+                .collect()
+        }
+
+        /// Finite values of either sign with magnitudes from 1e-6 to 1e6.
+        fn values() -> impl Strategy<Value = f64> {
+            (any::<bool>(), -6.0..6.0_f64).prop_map(|(negative, exponent)| {
+                let magnitude = 10.0_f64.powf(exponent);
+                if negative {
+                    -magnitude
+                } else {
+                    magnitude
+                }
+            })
+        }
+
+        proptest! {
+            #[test]
+            fn to_base_and_back(value in values()) {
+                for (category, unit) in all_units() {
+                    let base = category.to_base_map()[unit](value, unit).unwrap();
+                    let back = category.to_unit_map()[unit](base, unit).unwrap();
+                    // An affine unit shifts the value, so the round trip is only as exact as the
+                    // larger of the value and its offset base value; a reciprocal unit inverts
+                    // it twice, which is exact up to rounding like a proportional one.
+                    let scale = if category.is_affine(unit) {
+                        value.abs().max(base.abs())
+                    } else {
+                        value.abs()
+                    };
+                    prop_assert!(
+                        (back - value).abs() <= TOLERANCE * scale,
+                        "{} {}: {} came back as {}",
+                        category,
+                        unit,
+                        value,
+                        back
+                    );
+                }
+            }
+        }
+    }
+}
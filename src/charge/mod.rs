@@ -0,0 +1,47 @@
+//! This module offers electric charge unit conversion functions in double precision, i.e. f64.
+//! The elementary charge `e` doubles as the atomic unit of charge.
+
+use crate::table::conversion_table;
+
+pub(crate) const ELEMENTARY_CHARGE_TO_COULOMB: f64 = 1.602_176_634e-19;
+const STATCOULOMB_TO_COULOMB: f64 = 3.335_640_952e-10;
+
+conversion_table!(
+    kind = "electric charge",
+    category = Charge,
+    a_kind = "an electric charge",
+    base = "coulomb",
+    /// Returns the size of the charge unit in coulomb. `esu` is the CGS statcoulomb.
+    factor = coulombs_per,
+    to_base = to_coulomb,
+    from_base = from_coulomb,
+    to_map = convert_2_coulomb_from,
+    from_map = convert_from_coulomb_2,
+    units = {
+        "C" => 1.0,
+        "e" => ELEMENTARY_CHARGE_TO_COULOMB,
+        "esu" => STATCOULOMB_TO_COULOMB,
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn elementary_charge() {
+        assert_relative_eq!(
+            try_convert(1.0, "C", "e").unwrap(),
+            6.241e18,
+            max_relative = 1e-4
+        );
+        assert_relative_eq!(try_convert(1.0, "e", "C").unwrap(), 1.602_176_634e-19);
+        assert_relative_eq!(
+            try_convert(1.0, "e", "esu").unwrap(),
+            4.803_204_7e-10,
+            max_relative = 1e-7
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! This module offers conversions rejecting values that [`crate::convert`] passes on following
+//! IEEE 754, i.e. NaN and infinite values and the zero of reciprocal conversions.
+//!
+//! Negative values are converted as they are by both, e.g. a negative wavelength gives a
+//! negative photon energy, since offsets and signed differences are meaningful in most
+//! categories.
+
+use alloc::string::ToString;
+
+use crate::dispatch::Conversion;
+use crate::ConversionError;
+
+/// Converts `value` from the unit `from` to the unit `to` like [`crate::convert`], but returns
+/// `NonFinite` if `value` or the converted value is NaN or infinite, and `ReciprocalOfZero` if
+/// `value` is zero and the units are reciprocal, e.g. `nm` to `eV`.
+pub fn checked_convert(value: f64, from: &str, to: &str) -> Result<f64, ConversionError> {
+    let conversion = Conversion::resolve(from, to)?;
+    if !value.is_finite() {
+        return Err(ConversionError::NonFinite);
+    }
+    if value == 0.0 && conversion.is_reciprocal() {
+        return Err(ConversionError::ReciprocalOfZero(from.to_string()));
+    }
+    let converted = conversion.apply(value)?;
+    if converted.is_finite() {
+        Ok(converted)
+    } else {
+        Err(ConversionError::NonFinite)
+    }
+}
+
+/// Extends f64 with [`CheckedConvert::checked_convert`].
+pub trait CheckedConvert {
+    /// Converts the value from `from` to `to` with [`checked_convert`], e.g.
+    /// `500.0.checked_convert("nm", "eV")`.
+    fn checked_convert(self, from: &str, to: &str) -> Result<f64, ConversionError>;
+}
+
+impl CheckedConvert for f64 {
+    fn checked_convert(self, from: &str, to: &str) -> Result<f64, ConversionError> {
+        checked_convert(self, from, to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::{convert, energy};
+
+    #[test]
+    fn rejects_non_finite_values() {
+        assert!(convert(f64::NAN, "bohr", "ang").unwrap().is_nan());
+        assert_eq!(
+            checked_convert(f64::NAN, "bohr", "ang"),
+            Err(ConversionError::NonFinite)
+        );
+        assert_eq!(convert(f64::INFINITY, "eV", "rcm"), Ok(f64::INFINITY));
+        assert_eq!(
+            f64::INFINITY.checked_convert("eV", "rcm"),
+            Err(ConversionError::NonFinite)
+        );
+        assert_eq!(
+            checked_convert(f64::MAX, "pc", "fm"),
+            Err(ConversionError::NonFinite)
+        );
+        assert_eq!(
+            checked_convert(f64::NAN, "parsnip", "ang"),
+            Err(ConversionError::UnknownUnit("parsnip".to_string()))
+        );
+        assert_relative_eq!(
+            (-40.0).checked_convert("degC", "degF").unwrap(),
+            -40.0,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn rejects_the_reciprocal_of_zero() {
+        assert_eq!(energy::nm_2_ev(0.0, ""), Ok(f64::INFINITY));
+        assert_eq!(
+            checked_convert(0.0, "nm", "eV"),
+            Err(ConversionError::ReciprocalOfZero("nm".to_string()))
+        );
+        assert_eq!(
+            0.0.checked_convert("eV", "nm"),
+            Err(ConversionError::ReciprocalOfZero("eV".to_string()))
+        );
+        assert_eq!(checked_convert(0.0, "nm", "ang"), Ok(0.0));
+        assert_relative_eq!(
+            checked_convert(500.0, "nm", "eV").unwrap(),
+            energy::nm_2_ev(500.0, "").unwrap()
+        );
+    }
+}
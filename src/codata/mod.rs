@@ -0,0 +1,164 @@
+//! This module offers the physical constants that are revised between CODATA adjustments, in
+//! the revision selected by the features.
+//!
+//! The latest revision, CODATA 2022, is used by default or with the `codata_2022` feature. The
+//! `codata_2018` feature pins the 2018 values to reproduce older results; if both features are
+//! enabled, e.g. by `--all-features`, the latest revision wins. Constants fixed exactly by the SI,
+//! like the elementary charge, and the conversion functions are the same in every revision.
+
+#[cfg(all(feature = "codata_2018", not(feature = "codata_2022")))]
+pub(crate) use codata_2018::*;
+#[cfg(not(all(feature = "codata_2018", not(feature = "codata_2022"))))]
+pub(crate) use codata_2022::*;
+
+/// The 2018 CODATA recommended values.
+#[allow(dead_code)]
+mod codata_2018 {
+    pub(crate) const BOHR_RADIUS_TO_METRES: f64 = 5.291_772_109_03e-11;
+    pub(crate) const BOHR_RADIUS_TO_ANGSTROM: f64 = 0.529_177_210_903;
+    pub(crate) const HARTREE_EV_CONVERSION_FACTOR: f64 = 27.211_386_245_988;
+    pub(crate) const HARTREE_REC_CENTIMETRES_CONVERSION_FACTOR: f64 = 219_474.631_363_20;
+    pub(crate) const ELECTRON_MASS_TO_KILOGRAMS: f64 = 9.109_383_701_5e-31;
+    pub(crate) const ATOMIC_MASS_UNIT_TO_KILOGRAMS: f64 = 1.660_539_066_60e-27;
+    pub(crate) const ATOMIC_UNIT_OF_MAGNETIC_FLUX_DENSITY_TO_TESLA: f64 = 2.350_517_567_58e5;
+    pub(crate) const BOHR_MAGNETON_TO_JOULES_PER_TESLA: f64 = 9.274_010_078_3e-24;
+    pub(crate) const NUCLEAR_MAGNETON_TO_JOULES_PER_TESLA: f64 = 5.050_783_746_1e-27;
+    /// Decimal forms of the constants for the precise module.
+    pub(crate) const BOHR_RADIUS_TO_METRES_DECIMAL: &str = "5.29177210903e-11";
+    pub(crate) const BOHR_RADIUS_TO_ANGSTROM_DECIMAL: &str = "0.529177210903";
+    pub(crate) const HARTREE_EV_CONVERSION_FACTOR_DECIMAL: &str = "27.211386245988";
+    pub(crate) const HARTREE_REC_CENTIMETRES_CONVERSION_FACTOR_DECIMAL: &str = "219474.63136320";
+}
+
+/// The 2022 CODATA recommended values.
+#[allow(dead_code)]
+mod codata_2022 {
+    pub(crate) const BOHR_RADIUS_TO_METRES: f64 = 5.291_772_105_44e-11;
+    pub(crate) const BOHR_RADIUS_TO_ANGSTROM: f64 = 0.529_177_210_544;
+    pub(crate) const HARTREE_EV_CONVERSION_FACTOR: f64 = 27.211_386_245_981;
+    pub(crate) const HARTREE_REC_CENTIMETRES_CONVERSION_FACTOR: f64 = 219_474.631_363_14;
+    pub(crate) const ELECTRON_MASS_TO_KILOGRAMS: f64 = 9.109_383_713_9e-31;
+    pub(crate) const ATOMIC_MASS_UNIT_TO_KILOGRAMS: f64 = 1.660_539_068_92e-27;
+    pub(crate) const ATOMIC_UNIT_OF_MAGNETIC_FLUX_DENSITY_TO_TESLA: f64 = 2.350_517_570_77e5;
+    pub(crate) const BOHR_MAGNETON_TO_JOULES_PER_TESLA: f64 = 9.274_010_065_7e-24;
+    pub(crate) const NUCLEAR_MAGNETON_TO_JOULES_PER_TESLA: f64 = 5.050_783_739_3e-27;
+    /// Decimal forms of the constants for the precise module.
+    pub(crate) const BOHR_RADIUS_TO_METRES_DECIMAL: &str = "5.29177210544e-11";
+    pub(crate) const BOHR_RADIUS_TO_ANGSTROM_DECIMAL: &str = "0.529177210544";
+    pub(crate) const HARTREE_EV_CONVERSION_FACTOR_DECIMAL: &str = "27.211386245981";
+    pub(crate) const HARTREE_REC_CENTIMETRES_CONVERSION_FACTOR_DECIMAL: &str = "219474.63136314";
+}
+
+/// The values the tests expect in the selected revision, spelt out as the tabulated literals so
+/// a constant drifting from its revision is caught. The tests compare with these rather than
+/// with the constants under test.
+#[cfg(test)]
+pub(crate) mod expected {
+    #[cfg(all(feature = "codata_2018", not(feature = "codata_2022")))]
+    pub(crate) use super::expected_2018::*;
+    #[cfg(not(all(feature = "codata_2018", not(feature = "codata_2022"))))]
+    pub(crate) use super::expected_2022::*;
+}
+
+#[cfg(test)]
+#[allow(dead_code)]
+mod expected_2018 {
+    pub(crate) const BOHR_RADIUS_IN_METRES: f64 = 5.291_772_109_03e-11;
+    pub(crate) const BOHR_RADIUS_IN_KILOMETRES: f64 = 5.291_772_109_03e-14;
+    pub(crate) const BOHR_RADIUS_IN_GIGAMETRES: f64 = 5.291_772_109_03e-20;
+    pub(crate) const BOHR_RADIUS_IN_MICROMETRES: f64 = 5.291_772_109_03e-5;
+    pub(crate) const BOHR_RADIUS_IN_NANOMETRES: f64 = 5.291_772_109_03e-2;
+    pub(crate) const BOHR_RADIUS_IN_ANGSTROM: f64 = 0.529_177_210_903;
+    pub(crate) const BOHR_RADIUS_IN_PICOMETRES: f64 = 52.917_721_090_3;
+    pub(crate) const BOHR_RADIUS_IN_MILLIANGSTROM: f64 = 529.177_210_903;
+    pub(crate) const BOHR_RADIUS_IN_FEMTOMETRES: f64 = 52_917.721_090_3;
+    pub(crate) const BOHR_RADIUS_IN_ATTOMETRES: f64 = 52_917_721.090_3;
+    pub(crate) const BOHR_RADII_PER_DECAMETRE: f64 = 1.889_726_124_6e11;
+    pub(crate) const BOHR_RADIUS_IN_METRES_DECIMAL: &str = "5.29177210903e-11";
+    pub(crate) const BOHR_RADIUS_IN_ANGSTROM_DECIMAL: &str = "0.529177210903";
+    pub(crate) const HARTREE_IN_ELECTRONVOLTS: f64 = 27.211_386_245_988;
+    pub(crate) const ATOMIC_MASS_UNIT_IN_KILOGRAMS: f64 = 1.660_539_066_60e-27;
+    pub(crate) const ATOMIC_UNIT_OF_DIPOLE_IN_COULOMB_METRES: f64 = 8.478_353_625_5e-30;
+    pub(crate) const ATOMIC_UNIT_OF_POLARIZABILITY_IN_SI: f64 = 1.648_777_274_36e-41;
+    pub(crate) const ATOMIC_UNIT_OF_MAGNETIC_FLUX_DENSITY_IN_TESLA: f64 = 2.350_517_567_58e5;
+    pub(crate) const BOHR_MAGNETON_IN_JOULES_PER_TESLA: f64 = 9.274_010_078_3e-24;
+    pub(crate) const NUCLEAR_MAGNETON_IN_JOULES_PER_TESLA: f64 = 5.050_783_746_1e-27;
+}
+
+#[cfg(test)]
+#[allow(dead_code)]
+mod expected_2022 {
+    pub(crate) const BOHR_RADIUS_IN_METRES: f64 = 5.291_772_105_44e-11;
+    pub(crate) const BOHR_RADIUS_IN_KILOMETRES: f64 = 5.291_772_105_44e-14;
+    pub(crate) const BOHR_RADIUS_IN_GIGAMETRES: f64 = 5.291_772_105_44e-20;
+    pub(crate) const BOHR_RADIUS_IN_MICROMETRES: f64 = 5.291_772_105_44e-5;
+    pub(crate) const BOHR_RADIUS_IN_NANOMETRES: f64 = 5.291_772_105_44e-2;
+    pub(crate) const BOHR_RADIUS_IN_ANGSTROM: f64 = 0.529_177_210_544;
+    pub(crate) const BOHR_RADIUS_IN_PICOMETRES: f64 = 52.917_721_054_4;
+    pub(crate) const BOHR_RADIUS_IN_MILLIANGSTROM: f64 = 529.177_210_544;
+    pub(crate) const BOHR_RADIUS_IN_FEMTOMETRES: f64 = 52_917.721_054_4;
+    pub(crate) const BOHR_RADIUS_IN_ATTOMETRES: f64 = 52_917_721.054_4;
+    pub(crate) const BOHR_RADII_PER_DECAMETRE: f64 = 1.889_726_125_9e11;
+    pub(crate) const BOHR_RADIUS_IN_METRES_DECIMAL: &str = "5.29177210544e-11";
+    pub(crate) const BOHR_RADIUS_IN_ANGSTROM_DECIMAL: &str = "0.529177210544";
+    pub(crate) const HARTREE_IN_ELECTRONVOLTS: f64 = 27.211_386_245_981;
+    pub(crate) const ATOMIC_MASS_UNIT_IN_KILOGRAMS: f64 = 1.660_539_068_92e-27;
+    pub(crate) const ATOMIC_UNIT_OF_DIPOLE_IN_COULOMB_METRES: f64 = 8.478_353_619_8e-30;
+    pub(crate) const ATOMIC_UNIT_OF_POLARIZABILITY_IN_SI: f64 = 1.648_777_272_12e-41;
+    pub(crate) const ATOMIC_UNIT_OF_MAGNETIC_FLUX_DENSITY_IN_TESLA: f64 = 2.350_517_570_77e5;
+    pub(crate) const BOHR_MAGNETON_IN_JOULES_PER_TESLA: f64 = 9.274_010_065_7e-24;
+    pub(crate) const NUCLEAR_MAGNETON_IN_JOULES_PER_TESLA: f64 = 5.050_783_739_3e-27;
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn constants_are_the_selected_revision() {
+        assert_eq!(BOHR_RADIUS_TO_METRES, expected::BOHR_RADIUS_IN_METRES);
+        assert_eq!(
+            crate::length::BOHR_RADIUS_TO_METRES,
+            expected::BOHR_RADIUS_IN_METRES
+        );
+        assert_eq!(
+            BOHR_RADIUS_TO_METRES_DECIMAL,
+            expected::BOHR_RADIUS_IN_METRES_DECIMAL
+        );
+        assert_eq!(
+            HARTREE_EV_CONVERSION_FACTOR,
+            expected::HARTREE_IN_ELECTRONVOLTS
+        );
+    }
+
+    #[test]
+    fn revisions_agree_within_their_uncertainties() {
+        assert_relative_eq!(
+            codata_2018::BOHR_RADIUS_TO_METRES,
+            codata_2022::BOHR_RADIUS_TO_METRES,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(
+            BOHR_RADIUS_TO_ANGSTROM,
+            BOHR_RADIUS_TO_METRES / 1e-10,
+            max_relative = 1e-15
+        );
+        assert_relative_eq!(
+            codata_2018::ELECTRON_MASS_TO_KILOGRAMS,
+            codata_2022::ELECTRON_MASS_TO_KILOGRAMS,
+            max_relative = 1e-8
+        );
+        assert_relative_eq!(
+            codata_2018::BOHR_MAGNETON_TO_JOULES_PER_TESLA,
+            codata_2022::BOHR_MAGNETON_TO_JOULES_PER_TESLA,
+            max_relative = 1e-8
+        );
+        assert_relative_eq!(
+            codata_2018::ATOMIC_UNIT_OF_MAGNETIC_FLUX_DENSITY_TO_TESLA,
+            codata_2022::ATOMIC_UNIT_OF_MAGNETIC_FLUX_DENSITY_TO_TESLA,
+            max_relative = 1e-8
+        );
+    }
+}
@@ -0,0 +1,8 @@
+//! This module offers the map type backing the conversion tables.
+//! With the `std` feature it is a `HashMap`, without it the `BTreeMap` of `alloc`.
+
+#[cfg(feature = "std")]
+pub(crate) type Map<K, V> = std::collections::HashMap<K, V>;
+
+#[cfg(not(feature = "std"))]
+pub(crate) type Map<K, V> = alloc::collections::BTreeMap<K, V>;
@@ -0,0 +1,254 @@
+//! This module offers the parsing and conversion of compound units like `km/h`, `g/mol` or
+//! `kg·m/s` built from the units of the categories.
+//!
+//! A compound unit is a chain of components joined by `*` or `·` for products and `/` for
+//! quotients, read from left to right, so `m/s/s` is metres per second squared. A component is a
+//! unit of any category or a coherent SI unit like `m`, `g` or `mol` with an SI prefix, optionally
+//! raised to an integer power as in `m^2`, `s^-1`, `m²` or `s2`.
+
+use alloc::string::ToString;
+
+use num_traits::Float;
+
+use crate::energy::EV_JOULE_CONVERSION_FACTOR;
+use crate::lookup::get_converter;
+use crate::prefix::prefixed_factor;
+use crate::{Category, ConversionError, Dimension};
+
+/// A parsed compound unit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CompoundUnit {
+    /// Size of the unit in coherent SI units, e.g. `1e-3` for `g/mol` in kg/mol.
+    pub factor: f64,
+    /// Dimension of the unit.
+    pub dimension: Dimension,
+}
+
+/// Characters joining the components of a compound unit.
+const SEPARATORS: [char; 3] = ['*', '·', '/'];
+
+/// Coherent SI units, which are also understood with any SI prefix, e.g. `km` or `mmol`, with
+/// their size in coherent SI units and their dimension.
+const SI_UNITS: [(&str, f64, Dimension); 12] = [
+    ("m", 1.0, Category::Length.dimension()),
+    ("g", 1e-3, Category::Mass.dimension()),
+    ("s", 1.0, Category::Time.dimension()),
+    ("A", 1.0, Dimension::CURRENT),
+    ("mol", 1.0, Dimension::AMOUNT),
+    ("cd", 1.0, Dimension::LUMINOSITY),
+    ("J", 1.0, Category::Energy.dimension()),
+    ("N", 1.0, Category::Force.dimension()),
+    ("Pa", 1.0, Category::Pressure.dimension()),
+    ("W", 1.0, Category::Power.dimension()),
+    ("C", 1.0, Category::Charge.dimension()),
+    ("Hz", 1.0, Category::Frequency.dimension()),
+];
+
+/// Returns the size of the base unit of `category` in coherent SI units. Only energies are not
+/// based on their SI unit, but on the electronvolt.
+fn base_in_si(category: Category) -> f64 {
+    match category {
+        Category::Energy => EV_JOULE_CONVERSION_FACTOR,
+        _ => 1.0,
+    }
+}
+
+/// Returns the category resolving a component. The energy category also knows photon units like
+/// `Hz` as hν, which are equivalences rather than energies, so the other categories come first.
+fn component_category(unit: &str) -> Option<Category> {
+    let mut categories = Category::ALL.into_iter().filter(|c| c.contains(unit));
+    let first = categories.next()?;
+    match first {
+        Category::Energy => Some(categories.next().unwrap_or(first)),
+        _ => Some(first),
+    }
+}
+
+/// Resolves a component without exponent, returning `None` if it is unknown.
+fn resolve_unit(unit: &str) -> Option<Result<CompoundUnit, ConversionError>> {
+    if unit == "1" {
+        return Some(Ok(CompoundUnit {
+            factor: 1.0,
+            dimension: Dimension::DIMENSIONLESS,
+        }));
+    }
+    if let Some(category) = component_category(unit) {
+        if category.is_affine(unit) {
+            return Some(Err(ConversionError::AffineUnit(unit.to_string())));
+        }
+        if category.is_reciprocal(unit) {
+            return Some(Err(ConversionError::ReciprocalUnit(unit.to_string())));
+        }
+        return Some(
+            get_converter(category.to_base_map(), unit)
+                .and_then(|to_base| to_base(1.0, unit))
+                .map(|factor| CompoundUnit {
+                    factor: factor * base_in_si(category),
+                    dimension: category.dimension(),
+                }),
+        );
+    }
+    SI_UNITS.into_iter().find_map(|(symbol, size, dimension)| {
+        prefixed_factor(unit, symbol).map(|factor| {
+            Ok(CompoundUnit {
+                factor: factor * size,
+                dimension,
+            })
+        })
+    })
+}
+
+/// Splits the exponent off a component, e.g. `m^2`, `m²` or `m2` into `m` and 2.
+fn split_exponent(component: &str) -> Option<(&str, i8)> {
+    if let Some((unit, exponent)) = component.split_once('^') {
+        return Some((unit, exponent.parse().ok()?));
+    }
+    for (superscript, exponent) in [('²', 2), ('³', 3)] {
+        if let Some(unit) = component.strip_suffix(superscript) {
+            return Some((unit, exponent));
+        }
+    }
+    let unit = component.trim_end_matches(|c: char| c.is_ascii_digit());
+    if unit.is_empty() || unit == component {
+        return None;
+    }
+    Some((unit, component[unit.len()..].parse().ok()?))
+}
+
+/// Resolves one component of a compound unit, trying it as a registered unit before splitting
+/// off an exponent, so `cm²` is the area unit rather than the square of `cm`.
+fn resolve_component(component: &str) -> Result<CompoundUnit, ConversionError> {
+    let unknown = || ConversionError::UnknownUnit(component.to_string());
+    if let Some(unit) = resolve_unit(component) {
+        return unit;
+    }
+    let (unit, exponent) = split_exponent(component).ok_or_else(unknown)?;
+    let unit = resolve_unit(unit).ok_or_else(unknown)??;
+    Ok(CompoundUnit {
+        factor: Float::powi(unit.factor, i32::from(exponent)),
+        dimension: unit
+            .dimension
+            .checked_pow(exponent)
+            .ok_or_else(|| ConversionError::InvalidQuantity(component.to_string()))?,
+    })
+}
+
+/// Parses a compound unit like `km/h` into its size in coherent SI units and its dimension.
+///
+/// Returns `UnknownUnit` naming the first unknown component, and `AffineUnit` or
+/// `ReciprocalUnit` for components like `degC` that can not be scaled by a factor. Returns
+/// `InvalidQuantity` if an exponent of the dimension overflows, e.g. for `m^100*m^100`.
+pub fn parse_compound(unit: &str) -> Result<CompoundUnit, ConversionError> {
+    let mut compound = CompoundUnit {
+        factor: 1.0,
+        dimension: Dimension::DIMENSIONLESS,
+    };
+    // Each component is paired with the separator in front of it, the first one multiplies.
+    let separators = core::iter::once('*').chain(unit.chars().filter(|c| SEPARATORS.contains(c)));
+    for (separator, component) in separators.zip(unit.split(SEPARATORS)) {
+        let resolved = resolve_component(component)?;
+        let dimension = if separator == '/' {
+            compound.factor /= resolved.factor;
+            compound.dimension.checked_div(resolved.dimension)
+        } else {
+            compound.factor *= resolved.factor;
+            compound.dimension.checked_mul(resolved.dimension)
+        };
+        compound.dimension =
+            dimension.ok_or_else(|| ConversionError::InvalidQuantity(unit.to_string()))?;
+    }
+    Ok(compound)
+}
+
+/// Converts `value` from the compound unit `from` to the compound unit `to`, e.g. from `km/h`
+/// to `m/s`. Returns `IncompatibleDimensions` if the units differ in dimension.
+pub fn convert_compound(value: f64, from: &str, to: &str) -> Result<f64, ConversionError> {
+    let from_unit = parse_compound(from)?;
+    let to_unit = parse_compound(to)?;
+    if from_unit.dimension != to_unit.dimension {
+        return Err(ConversionError::IncompatibleDimensions {
+            from: from_unit.dimension,
+            to: to_unit.dimension,
+        });
+    }
+    Ok(value * from_unit.factor / to_unit.factor)
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn speeds_and_molar_masses() {
+        assert_relative_eq!(convert_compound(36.0, "km/h", "m/s").unwrap(), 10.0);
+        assert_relative_eq!(convert_compound(18.0, "g/mol", "kg/mol").unwrap(), 0.018);
+        assert_eq!(
+            parse_compound("g/mol").unwrap().dimension,
+            Dimension::MASS / Dimension::AMOUNT
+        );
+        assert_relative_eq!(convert_compound(1.0, "kJ/mol", "J/mmol").unwrap(), 1.0);
+    }
+
+    #[test]
+    fn exponents_and_chained_denominators() {
+        let acceleration = parse_compound("m/s/s").unwrap();
+        assert_eq!(acceleration.dimension, Category::Acceleration.dimension());
+        assert_eq!(
+            parse_compound("m*s^-2").unwrap().dimension,
+            acceleration.dimension
+        );
+        assert_eq!(parse_compound("m/s2").unwrap(), acceleration);
+        assert_eq!(
+            parse_compound("kg·m/s").unwrap().dimension,
+            Category::Momentum.dimension()
+        );
+        assert_relative_eq!(parse_compound("cm^2").unwrap().factor, 1e-4);
+        assert_relative_eq!(parse_compound("cm²").unwrap().factor, 1e-4);
+        assert_relative_eq!(parse_compound("1/kHz").unwrap().factor, 1e-3);
+        assert_relative_eq!(
+            convert_compound(1.0, "eV/ang", "N").unwrap(),
+            1.602_176_634e-9
+        );
+    }
+
+    #[test]
+    fn overflowing_exponents_are_errors() {
+        assert_eq!(
+            parse_compound("m^100*m^100"),
+            Err(ConversionError::InvalidQuantity("m^100*m^100".to_string()))
+        );
+        assert_eq!(
+            parse_compound("s^-100/s^100"),
+            Err(ConversionError::InvalidQuantity("s^-100/s^100".to_string()))
+        );
+        assert_eq!(
+            parse_compound("m^100/m^100").unwrap().dimension,
+            Dimension::DIMENSIONLESS
+        );
+    }
+
+    #[test]
+    fn unknown_and_incompatible_components() {
+        assert_eq!(
+            parse_compound("parsnip/s"),
+            Err(ConversionError::UnknownUnit("parsnip".to_string()))
+        );
+        assert_eq!(
+            parse_compound("m^x"),
+            Err(ConversionError::UnknownUnit("m^x".to_string()))
+        );
+        assert_eq!(
+            parse_compound("J/degC"),
+            Err(ConversionError::AffineUnit("degC".to_string()))
+        );
+        assert_eq!(
+            convert_compound(1.0, "m/s", "kg"),
+            Err(ConversionError::IncompatibleDimensions {
+                from: Category::Speed.dimension(),
+                to: Dimension::MASS,
+            })
+        );
+    }
+}
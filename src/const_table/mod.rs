@@ -0,0 +1,45 @@
+//! This module offers the conversion factors of the length and energy units as a static table
+//! generated by the build script from `factors.in`, so they need no map to be built at run time.
+
+include!(concat!(env!("OUT_DIR"), "/const_table.rs"));
+
+/// Returns the factor `f` with `convert(v, from, to) == v * f` from the static table, or `None`
+/// if the units are not both proportional length or both proportional energy units.
+///
+/// The factors multiply the size of `from` in the base unit by the size of the base unit in `to`,
+/// so they can differ from the conversion maps in the last digit where those divide by the size
+/// of `to` instead.
+pub fn lookup_const(from: &str, to: &str) -> Option<f64> {
+    CONST_TABLE
+        .binary_search_by(|&(f, t, _)| (f, t).cmp(&(from, to)))
+        .ok()
+        .map(|i| CONST_TABLE[i].2)
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::{energy, length};
+
+    #[test]
+    fn matches_the_conversion_maps() {
+        for &(from, to, factor) in CONST_TABLE.iter() {
+            let expected = length::try_convert(1.0, from, to)
+                .or_else(|_| energy::try_convert(1.0, from, to))
+                .unwrap();
+            assert_relative_eq!(factor, expected, max_relative = 1e-9);
+        }
+        assert_eq!(
+            lookup_const("bohr", "ang"),
+            Some(length::BOHR_RADIUS_TO_METRES * (1.0 / length::ANGSTROEM))
+        );
+        assert_eq!(lookup_const("eV", "eV"), Some(1.0));
+        assert_eq!(lookup_const("nm", "eV"), None);
+        assert_eq!(lookup_const("bohr", "eV"), None);
+        assert!(CONST_TABLE
+            .windows(2)
+            .all(|w| (w[0].0, w[0].1) < (w[1].0, w[1].1)));
+    }
+}
@@ -0,0 +1,96 @@
+//! This module offers a converter between two units that is resolved once and then applied to
+//! many values, e.g. in hot loops.
+
+use alloc::string::{String, ToString};
+
+use crate::dispatch::Conversion;
+use crate::ConversionError;
+
+/// A conversion from one unit to another, resolved at construction.
+///
+/// [`crate::convert`] searches the categories and does two map lookups per call. A converter does
+/// this once in [`Converter::new`] and stores the factor, or for affine and reciprocal units the
+/// conversion functions, so applying it is a multiplication or two function calls.
+#[derive(Clone)]
+pub struct Converter {
+    from: String,
+    to: String,
+    conversion: Conversion,
+}
+
+impl Converter {
+    /// Resolves the conversion from `from` to `to`. Returns the error [`crate::convert`] would
+    /// give, e.g. `UnknownUnit` or `DimensionMismatch`.
+    pub fn new(from: &str, to: &str) -> Result<Self, ConversionError> {
+        Ok(Converter {
+            conversion: Conversion::resolve(from, to)?,
+            from: from.to_string(),
+            to: to.to_string(),
+        })
+    }
+
+    /// Returns the unit converted from.
+    pub fn from_unit(&self) -> &str {
+        &self.from
+    }
+
+    /// Returns the unit converted to.
+    pub fn to_unit(&self) -> &str {
+        &self.to
+    }
+
+    /// Converts `value`.
+    pub fn apply(&self, value: f64) -> f64 {
+        self.conversion
+            .apply(value)
+            .expect("resolved units convert every value")
+    }
+
+    /// Converts every value of `values` in place.
+    pub fn apply_slice(&self, values: &mut [f64]) {
+        match self.conversion {
+            Conversion::Factor(factor) => values.iter_mut().for_each(|v| *v *= factor),
+            _ => values.iter_mut().for_each(|v| *v = self.apply(*v)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::convert;
+
+    #[test]
+    fn matches_one_shot_conversions() {
+        for (from, to) in [
+            ("bohr", "ang"),
+            ("eV", "rcm"),
+            ("degC", "degF"),
+            ("nm", "eV"),
+        ] {
+            let converter = Converter::new(from, to).unwrap();
+            assert_eq!((converter.from_unit(), converter.to_unit()), (from, to));
+            let mut values = vec![0.5, 1.0, 250.0];
+            let expected: Vec<f64> = values
+                .iter()
+                .map(|&v| convert(v, from, to).unwrap())
+                .collect();
+            for (&v, &e) in values.iter().zip(&expected) {
+                assert_relative_eq!(converter.apply(v), e);
+            }
+            converter.apply_slice(&mut values);
+            for (v, e) in values.into_iter().zip(expected) {
+                assert_relative_eq!(v, e);
+            }
+        }
+        assert_eq!(
+            Converter::new("parsnip", "ang").err(),
+            Some(ConversionError::UnknownUnit("parsnip".to_string()))
+        );
+    }
+}
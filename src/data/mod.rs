@@ -0,0 +1,61 @@
+//! This module offers data size unit conversion functions in double precision, i.e. f64.
+//! Decimal prefixes (`kB`, `MB`, ...) are powers of 1000 and binary prefixes (`KiB`, `MiB`, ...)
+//! powers of 1024; the two are never aliased, so `KB` is a decimal kilobyte like `kB`.
+
+use crate::table::conversion_table;
+
+const BITS_PER_BYTE: f64 = 8.0;
+const KILO: f64 = 1e3;
+const MEGA: f64 = 1e6;
+const GIGA: f64 = 1e9;
+const TERA: f64 = 1e12;
+const KIBI: f64 = 1_024.0;
+const MEBI: f64 = KIBI * KIBI;
+const GIBI: f64 = MEBI * KIBI;
+const TEBI: f64 = GIBI * KIBI;
+
+conversion_table!(
+    kind = "data size",
+    category = Data,
+    a_kind = "a data size",
+    base = "bits",
+    /// Returns the size of the data size unit in bits.
+    factor = bits_per,
+    to_base = to_bits,
+    from_base = from_bits,
+    to_map = convert_2_bit_from,
+    from_map = convert_from_bit_2,
+    units = {
+        "bit" => 1.0,
+        "kbit" => KILO,
+        "Mbit" => MEGA,
+        "Gbit" => GIGA,
+        "B" => BITS_PER_BYTE,
+        "kB" => KILO * BITS_PER_BYTE,
+        "KB" => KILO * BITS_PER_BYTE,
+        "MB" => MEGA * BITS_PER_BYTE,
+        "GB" => GIGA * BITS_PER_BYTE,
+        "TB" => TERA * BITS_PER_BYTE,
+        "KiB" => KIBI * BITS_PER_BYTE,
+        "MiB" => MEBI * BITS_PER_BYTE,
+        "GiB" => GIBI * BITS_PER_BYTE,
+        "TiB" => TEBI * BITS_PER_BYTE,
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn binary_and_decimal_prefixes_stay_distinct() {
+        assert_eq!(convert_2_bit_from()["KiB"](1.0, "KiB"), Ok(8_192.0));
+        assert_eq!(convert_2_bit_from()["KB"](1.0, "KB"), Ok(8_000.0));
+        assert_eq!(try_convert(1.0, "KB", "kB"), Ok(1.0));
+        assert_relative_eq!(try_convert(1.0, "KiB", "KB").unwrap(), 1.024);
+        assert_relative_eq!(try_convert(1.0, "GiB", "MiB").unwrap(), 1_024.0);
+        assert_relative_eq!(try_convert(1.0, "GB", "Mbit").unwrap(), 8_000.0);
+    }
+}
@@ -0,0 +1,193 @@
+//! This module offers the `Dimension` of a physical quantity as exponents of the seven SI base
+//! quantities, for dimensional analysis of the categories.
+
+use core::fmt;
+use core::ops::{Div, Mul};
+
+use num_traits::Pow;
+
+/// The dimension of a quantity, i.e. the exponents of the SI base quantities length, mass, time,
+/// electric current, temperature, amount of substance and luminous intensity.
+///
+/// Dimensions multiply and divide like the quantities, so `Dimension::LENGTH / Dimension::TIME`
+/// is the dimension of a speed. Distinct categories may share a dimension, e.g. angles and data
+/// sizes are both dimensionless, so conversions are still routed by [`crate::Category`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dimension {
+    pub length: i8,
+    pub mass: i8,
+    pub time: i8,
+    pub current: i8,
+    pub temperature: i8,
+    pub amount: i8,
+    pub luminosity: i8,
+}
+
+impl Dimension {
+    /// The dimension of a pure number.
+    pub const DIMENSIONLESS: Dimension = Dimension::new(0, 0, 0, 0, 0, 0, 0);
+    pub const LENGTH: Dimension = Dimension::new(1, 0, 0, 0, 0, 0, 0);
+    pub const MASS: Dimension = Dimension::new(0, 1, 0, 0, 0, 0, 0);
+    pub const TIME: Dimension = Dimension::new(0, 0, 1, 0, 0, 0, 0);
+    pub const CURRENT: Dimension = Dimension::new(0, 0, 0, 1, 0, 0, 0);
+    pub const TEMPERATURE: Dimension = Dimension::new(0, 0, 0, 0, 1, 0, 0);
+    pub const AMOUNT: Dimension = Dimension::new(0, 0, 0, 0, 0, 1, 0);
+    pub const LUMINOSITY: Dimension = Dimension::new(0, 0, 0, 0, 0, 0, 1);
+
+    /// Creates the dimension with the exponents of length, mass, time, current, temperature,
+    /// amount and luminosity.
+    pub const fn new(
+        length: i8,
+        mass: i8,
+        time: i8,
+        current: i8,
+        temperature: i8,
+        amount: i8,
+        luminosity: i8,
+    ) -> Self {
+        Dimension {
+            length,
+            mass,
+            time,
+            current,
+            temperature,
+            amount,
+            luminosity,
+        }
+    }
+
+    /// Returns whether all exponents are zero.
+    pub fn is_dimensionless(self) -> bool {
+        self == Dimension::DIMENSIONLESS
+    }
+
+    /// Multiplies the dimensions, or returns `None` if an exponent overflows.
+    pub fn checked_mul(self, rhs: Dimension) -> Option<Dimension> {
+        self.try_zip_with(rhs, i8::checked_add)
+    }
+
+    /// Divides the dimensions, or returns `None` if an exponent overflows.
+    pub fn checked_div(self, rhs: Dimension) -> Option<Dimension> {
+        self.try_zip_with(rhs, i8::checked_sub)
+    }
+
+    /// Raises the dimension to the power `rhs`, or returns `None` if an exponent overflows.
+    pub fn checked_pow(self, rhs: i8) -> Option<Dimension> {
+        self.try_zip_with(Dimension::DIMENSIONLESS, |a, _| a.checked_mul(rhs))
+    }
+
+    /// Applies `f` to the exponents of `self` and `other` pairwise, or returns `None` if it does
+    /// for any of them.
+    fn try_zip_with(self, other: Dimension, f: impl Fn(i8, i8) -> Option<i8>) -> Option<Dimension> {
+        Some(Dimension::new(
+            f(self.length, other.length)?,
+            f(self.mass, other.mass)?,
+            f(self.time, other.time)?,
+            f(self.current, other.current)?,
+            f(self.temperature, other.temperature)?,
+            f(self.amount, other.amount)?,
+            f(self.luminosity, other.luminosity)?,
+        ))
+    }
+}
+
+/// Panics if an exponent overflows, use [`Dimension::checked_mul`] for untrusted exponents.
+impl Mul for Dimension {
+    type Output = Dimension;
+
+    fn mul(self, rhs: Dimension) -> Dimension {
+        self.checked_mul(rhs).expect("dimension exponent overflow")
+    }
+}
+
+/// Panics if an exponent overflows, use [`Dimension::checked_div`] for untrusted exponents.
+impl Div for Dimension {
+    type Output = Dimension;
+
+    fn div(self, rhs: Dimension) -> Dimension {
+        self.checked_div(rhs).expect("dimension exponent overflow")
+    }
+}
+
+/// Panics if an exponent overflows, use [`Dimension::checked_pow`] for untrusted exponents.
+impl Pow<i8> for Dimension {
+    type Output = Dimension;
+
+    fn pow(self, rhs: i8) -> Dimension {
+        self.checked_pow(rhs).expect("dimension exponent overflow")
+    }
+}
+
+/// Writes the dimension in SI base units, e.g. `m^2 kg s^-2` for an energy, or `1` if it is
+/// dimensionless.
+impl fmt::Display for Dimension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_dimensionless() {
+            return f.write_str("1");
+        }
+        let mut separator = "";
+        for (symbol, exponent) in [
+            ("m", self.length),
+            ("kg", self.mass),
+            ("s", self.time),
+            ("A", self.current),
+            ("K", self.temperature),
+            ("mol", self.amount),
+            ("cd", self.luminosity),
+        ] {
+            match exponent {
+                0 => continue,
+                1 => write!(f, "{}{}", separator, symbol)?,
+                _ => write!(f, "{}{}^{}", separator, symbol, exponent)?,
+            }
+            separator = " ";
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::Category;
+
+    #[test]
+    fn derived_dimensions() {
+        assert_eq!(
+            Category::Length.dimension() * Category::Length.dimension(),
+            Category::Area.dimension()
+        );
+        // Work is force along a distance and shares the dimension of energy.
+        let work = Category::Force.dimension() * Category::Length.dimension();
+        assert_eq!(work, Category::Energy.dimension());
+        assert_eq!(
+            Category::Energy.dimension() / Category::Time.dimension(),
+            Category::Power.dimension()
+        );
+        assert_eq!(
+            Dimension::LENGTH / Dimension::TIME,
+            Category::Speed.dimension()
+        );
+        assert_eq!(Dimension::TIME.pow(-1), Category::Frequency.dimension());
+        assert!(Category::Angle.dimension().is_dimensionless());
+        assert_ne!(Category::Mass.dimension(), Category::Momentum.dimension());
+        assert_eq!(Category::Energy.dimension().to_string(), "m^2 kg s^-2");
+        assert_eq!(Dimension::DIMENSIONLESS.to_string(), "1");
+    }
+
+    #[test]
+    fn checked_arithmetic_reports_overflow() {
+        let large = Dimension::LENGTH.pow(100);
+        assert_eq!(large.checked_mul(large), None);
+        assert_eq!(large.checked_div(large), Some(Dimension::DIMENSIONLESS));
+        assert_eq!(Dimension::LENGTH.pow(-100).checked_div(large), None);
+        assert_eq!(large.checked_pow(2), None);
+        assert_eq!(
+            Dimension::TIME.checked_pow(-1),
+            Some(Dimension::TIME.pow(-1))
+        );
+    }
+}
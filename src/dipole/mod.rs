@@ -0,0 +1,110 @@
+//! This module offers dipole moment unit conversion functions in double precision, i.e. f64.
+//! The atomic unit of the dipole moment is e·a₀.
+//! It also converts molecular polarizabilities, whose atomic unit is the volume a₀³.
+
+use crate::charge::ELEMENTARY_CHARGE_TO_COULOMB;
+use crate::codata::{BOHR_RADIUS_TO_ANGSTROM, BOHR_RADIUS_TO_METRES, HARTREE_EV_CONVERSION_FACTOR};
+use crate::table::conversion_table;
+
+const DEBYE_TO_COULOMB_METRES: f64 = 3.335_640_952e-30;
+const ATOMIC_UNIT_TO_COULOMB_METRES: f64 = ELEMENTARY_CHARGE_TO_COULOMB * BOHR_RADIUS_TO_METRES;
+/// Polarizability volume of one atomic unit in Å³, i.e. a₀³.
+const POLARIZABILITY_ATOMIC_UNIT_TO_CUBIC_ANGSTROEM: f64 =
+    BOHR_RADIUS_TO_ANGSTROM * BOHR_RADIUS_TO_ANGSTROM * BOHR_RADIUS_TO_ANGSTROM;
+/// Polarizability of one atomic unit in C·m²/V, i.e. e²a₀²/E_h with E_h given in eV.
+const POLARIZABILITY_ATOMIC_UNIT_TO_SI: f64 =
+    ELEMENTARY_CHARGE_TO_COULOMB * BOHR_RADIUS_TO_METRES * BOHR_RADIUS_TO_METRES
+        / HARTREE_EV_CONVERSION_FACTOR;
+
+conversion_table!(
+    kind = "dipole moment",
+    category = Dipole,
+    a_kind = "a dipole moment",
+    base = "coulomb metres",
+    /// Returns the size of the dipole moment unit in coulomb metres.
+    factor = coulomb_metres_per,
+    to_base = to_coulomb_metres,
+    from_base = from_coulomb_metres,
+    to_map = convert_2_cm_from,
+    from_map = convert_from_cm_2,
+    units = {
+        "Cm" => 1.0,
+        "D" => DEBYE_TO_COULOMB_METRES,
+        "au" => ATOMIC_UNIT_TO_COULOMB_METRES,
+    }
+);
+
+/// Function to convert a polarizability in atomic units to a polarizability volume in Å³.
+/// # Arguments
+///  * `alpha` - polarizability in atomic units
+///
+pub fn au_polarizability_to_ang3(alpha: f64) -> f64 {
+    alpha * POLARIZABILITY_ATOMIC_UNIT_TO_CUBIC_ANGSTROEM
+}
+
+/// Function to convert a polarizability volume in Å³ to atomic units.
+/// # Arguments
+///  * `alpha` - polarizability volume in Å³
+///
+pub fn ang3_polarizability_to_au(alpha: f64) -> f64 {
+    alpha / POLARIZABILITY_ATOMIC_UNIT_TO_CUBIC_ANGSTROEM
+}
+
+/// Function to convert a polarizability in atomic units to SI, i.e. C·m²/V.
+/// # Arguments
+///  * `alpha` - polarizability in atomic units
+///
+pub fn au_polarizability_to_si(alpha: f64) -> f64 {
+    alpha * POLARIZABILITY_ATOMIC_UNIT_TO_SI
+}
+
+/// Function to convert a polarizability in C·m²/V to atomic units.
+/// # Arguments
+///  * `alpha` - polarizability in C·m²/V
+///
+pub fn si_polarizability_to_au(alpha: f64) -> f64 {
+    alpha / POLARIZABILITY_ATOMIC_UNIT_TO_SI
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::codata::expected;
+
+    #[test]
+    fn atomic_units_to_debye() {
+        assert_relative_eq!(
+            try_convert(1.0, "au", "D").unwrap(),
+            2.5417,
+            max_relative = 1e-4
+        );
+        assert_relative_eq!(try_convert(1.0, "D", "Cm").unwrap(), 3.335_640_952e-30);
+    }
+
+    #[test]
+    fn polarizability_atomic_unit() {
+        assert_relative_eq!(au_polarizability_to_ang3(1.0), 0.1482, max_relative = 1e-3);
+        assert_relative_eq!(
+            au_polarizability_to_ang3(1.0),
+            crate::length::bohr_to_ang_const(1.0).powi(3),
+            max_relative = 1e-7
+        );
+        assert_relative_eq!(si_polarizability_to_au(au_polarizability_to_si(4.2)), 4.2);
+    }
+
+    #[test]
+    fn atomic_units_are_the_selected_revision() {
+        assert_relative_eq!(
+            try_convert(1.0, "au", "Cm").unwrap(),
+            expected::ATOMIC_UNIT_OF_DIPOLE_IN_COULOMB_METRES,
+            max_relative = 1e-10
+        );
+        assert_relative_eq!(
+            au_polarizability_to_si(1.0),
+            expected::ATOMIC_UNIT_OF_POLARIZABILITY_IN_SI,
+            max_relative = 1e-10
+        );
+    }
+}
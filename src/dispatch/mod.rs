@@ -0,0 +1,512 @@
+//! This module offers the crate level conversion entry point routing to the category modules.
+
+use alloc::string::ToString;
+
+use crate::cache;
+use crate::category::Callback;
+use crate::lookup::get_converter;
+use crate::prefix::split_si_prefix;
+use crate::{Category, ConversionError};
+
+/// Converts `value` from the unit `from` to the unit `to`, figuring out the category from the
+/// unit names.
+///
+/// Some units are known to several categories, e.g. `nm` is a length and, as a photon
+/// wavelength, an energy. The first category knowing both units is used, so `nm` to `ang` is a
+/// length conversion while `nm` to `eV` is an energy conversion. Energy only comes last, so `Hz`
+/// to `rad/s` is a frequency conversion rather than going through h and ħ. If the units are known
+/// but share no category, `DimensionMismatch` is returned.
+///
+/// A unit unknown to the categories may also be an SI prefix in front of a known unit, e.g. `km`,
+/// `Gm` or `keV`, which scales that unit. A prefixed unit known to a category only, e.g. `kHz`,
+/// is split the same way if that makes it share a category with the other unit, so `kHz` to `eV`
+/// converts like `Hz` to `eV`.
+///
+/// Proportional conversions multiply by the [`conversion_factor`], which is cached per unit pair
+/// with the `std` feature, so repeated conversions of the same pair skip the category search.
+/// With the `const-table` feature, length and energy factors come from a table compiled into the
+/// binary instead, see [`crate::lookup_const`].
+///
+/// NaN and infinite values are converted following IEEE 754, and zero converted between
+/// reciprocal units gives infinity; use [`crate::checked_convert`] to get an error instead.
+pub fn convert(value: f64, from: &str, to: &str) -> Result<f64, ConversionError> {
+    #[cfg(feature = "const-table")]
+    if let Some(factor) = crate::lookup_const(from, to) {
+        return Ok(value * factor);
+    }
+    if let Some(factor) = cache::cached_factor(from, to) {
+        return Ok(value * factor);
+    }
+    match conversion_factor(from, to) {
+        Ok(factor) => {
+            cache::store_factor(from, to, factor);
+            Ok(value * factor)
+        }
+        Err(ConversionError::AffineUnit(_) | ConversionError::ReciprocalUnit(_)) => {
+            Conversion::resolve(from, to)?.apply(value)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Converts a value between two units like [`convert`], written inline as
+/// `convert!(2.0, "eV" => "rcm")` or `convert!(2.0 "eV" => "rcm")`.
+///
+/// The units are looked up at run time, so an unknown unit gives the `Result` of [`convert`]
+/// with `UnknownUnit` naming it rather than failing to compile.
+#[macro_export]
+macro_rules! convert {
+    ($value:literal $from:literal => $to:expr) => {
+        $crate::convert($value, $from, $to)
+    };
+    ($value:expr, $from:expr => $to:expr) => {
+        $crate::convert($value, $from, $to)
+    };
+}
+
+/// Returns the factor `f` with `convert(v, from, to) == v * f`, e.g. to scale an array without
+/// converting each value.
+///
+/// Affine units like `degC` have no such factor and give `AffineUnit`. Reciprocal units like
+/// `nm` as a photon energy give `ReciprocalUnit` unless both units are reciprocal, in which case
+/// the conversion is proportional again.
+pub fn conversion_factor(from: &str, to: &str) -> Result<f64, ConversionError> {
+    let (category, (from_scale, from_unit), (to_scale, to_unit)) = resolve_units(from, to)?;
+    if let Some(unit) = [(from, from_unit), (to, to_unit)]
+        .into_iter()
+        .find(|(_, u)| category.is_affine(u))
+    {
+        return Err(ConversionError::AffineUnit(unit.0.to_string()));
+    }
+    match (
+        category.is_reciprocal(from_unit),
+        category.is_reciprocal(to_unit),
+    ) {
+        (true, false) => Err(ConversionError::ReciprocalUnit(from.to_string())),
+        (false, true) => Err(ConversionError::ReciprocalUnit(to.to_string())),
+        _ => Ok(category.try_convert(from_scale, from_unit, to_unit)? / to_scale),
+    }
+}
+
+/// Converts every value of `values` from `from` to `to` and writes the results to `out`.
+///
+/// The units are resolved once for the whole slice. For proportional units the loop is a single
+/// multiplication per value, which the compiler can vectorise, whereas calling [`convert`] per
+/// value searches the categories and does two map lookups every time; for large slices this is
+/// typically faster by an order of magnitude. Affine and reciprocal units go through the
+/// conversion functions of the units, which are still only looked up once.
+///
+/// # Panics
+/// Panics if `values` and `out` differ in length.
+pub fn convert_slice(
+    values: &[f64],
+    from: &str,
+    to: &str,
+    out: &mut [f64],
+) -> Result<(), ConversionError> {
+    assert_eq!(
+        values.len(),
+        out.len(),
+        "input and output slices differ in length"
+    );
+    out.copy_from_slice(values);
+    convert_slice_mut(out, from, to)
+}
+
+/// Converts every value of `values` in place from `from` to `to`, see [`convert_slice`].
+/// If an error is returned, the values may be partially converted.
+pub fn convert_slice_mut(values: &mut [f64], from: &str, to: &str) -> Result<(), ConversionError> {
+    match Conversion::resolve(from, to)? {
+        Conversion::Factor(factor) => {
+            values.iter_mut().for_each(|v| *v *= factor);
+            Ok(())
+        }
+        conversion => {
+            for v in values.iter_mut() {
+                *v = conversion.apply(*v)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// A conversion between two units resolved once, to be applied to many values.
+#[derive(Clone, Copy)]
+pub(crate) enum Conversion {
+    /// Proportional units, the value is multiplied by the factor.
+    Factor(f64),
+    /// Affine or reciprocal units, the value goes through the conversion functions of the units,
+    /// which are passed their keys. The scales are the factors of SI prefixes put in front of
+    /// the keys, e.g. `1e3` for `keV`.
+    Callbacks {
+        to_base: Callback,
+        from_base: Callback,
+        from_key: &'static str,
+        to_key: &'static str,
+        from_scale: f64,
+        to_scale: f64,
+        reciprocal: bool,
+    },
+}
+
+impl Conversion {
+    /// Looks up the conversion from `from` to `to`.
+    pub(crate) fn resolve(from: &str, to: &str) -> Result<Self, ConversionError> {
+        let reciprocal = match conversion_factor(from, to) {
+            Ok(factor) => return Ok(Conversion::Factor(factor)),
+            Err(ConversionError::AffineUnit(_)) => false,
+            Err(ConversionError::ReciprocalUnit(_)) => true,
+            Err(e) => return Err(e),
+        };
+        let (category, (from_scale, from_unit), (to_scale, to_unit)) = resolve_units(from, to)?;
+        Ok(Conversion::Callbacks {
+            to_base: get_converter(category.to_base_map(), from_unit)?,
+            from_base: get_converter(category.to_unit_map(), to_unit)?,
+            from_key: static_key(category, from_unit)?,
+            to_key: static_key(category, to_unit)?,
+            from_scale,
+            to_scale,
+            reciprocal,
+        })
+    }
+
+    /// Converts `value` between the units the conversion was resolved for.
+    pub(crate) fn apply(self, value: f64) -> Result<f64, ConversionError> {
+        match self {
+            Conversion::Factor(factor) => Ok(value * factor),
+            Conversion::Callbacks {
+                to_base,
+                from_base,
+                from_key,
+                to_key,
+                from_scale,
+                to_scale,
+                ..
+            } => Ok(from_base(to_base(value * from_scale, from_key)?, to_key)? / to_scale),
+        }
+    }
+
+    /// Returns whether the conversion is inversely proportional, e.g. `nm` to `eV`.
+    pub(crate) fn is_reciprocal(self) -> bool {
+        matches!(
+            self,
+            Conversion::Callbacks {
+                reciprocal: true,
+                ..
+            }
+        )
+    }
+
+    /// Returns the derivative of the conversion at `value`. Proportional and affine conversions
+    /// have a constant slope, the slope of a reciprocal conversion `y = k/x` is `-y/x`.
+    pub(crate) fn slope(self, value: f64) -> Result<f64, ConversionError> {
+        match self {
+            Conversion::Factor(factor) => Ok(factor),
+            Conversion::Callbacks {
+                reciprocal: true, ..
+            } => Ok(-self.apply(value)? / value),
+            Conversion::Callbacks {
+                reciprocal: false, ..
+            } => Ok(self.apply(1.0)? - self.apply(0.0)?),
+        }
+    }
+}
+
+/// Returns the key `unit` is registered under in the conversion maps of `category`.
+fn static_key(category: Category, unit: &str) -> Result<&'static str, ConversionError> {
+    category
+        .to_base_map()
+        .get_key_value(unit)
+        .map(|(key, _)| *key)
+        .ok_or_else(|| ConversionError::UnknownUnit(unit.to_string()))
+}
+
+/// The factor of an SI prefix and the unit it is put in front of, e.g. `(1e3, "Hz")` for `kHz`.
+pub(crate) type Reading<'a> = (f64, &'a str);
+
+/// Splits a unit unknown to the categories but made of an SI prefix and a known unit, e.g. `km`
+/// or `keV`, into the factor of the prefix and the known unit. Every other unit is returned with
+/// the factor one.
+pub(crate) fn unprefixed(unit: &str) -> Reading<'_> {
+    if Category::of(unit).is_some() {
+        return (1.0, unit);
+    }
+    split_si_prefix(unit, |rest| Category::of(rest).is_some()).unwrap_or((1.0, unit))
+}
+
+/// Returns the ways to read `unit`: as [`unprefixed`] gives it and, if it is a prefixed unit
+/// whose category also knows the unit without the prefix, e.g. `kHz`, split into the prefix and
+/// that unit. Splits into units of another category, like `min` into milli-inches, are not tried.
+fn readings(unit: &str) -> impl Iterator<Item = Reading<'_>> {
+    let written = unprefixed(unit);
+    let split = split_si_prefix(unit, |rest| {
+        Category::ALL
+            .into_iter()
+            .any(|c| c.contains(unit) && c.contains(rest))
+    })
+    .filter(|&split| split != written);
+    core::iter::once(written).chain(split)
+}
+
+/// Returns the first category knowing both units, see [`preferred_category`].
+fn shared_category(from: &str, to: &str) -> Option<Category> {
+    preferred_category(|c| c.contains(from) && c.contains(to))
+}
+
+/// Returns the first category `knows` holds for, giving any other category precedence over
+/// energy. The energy category also knows photon units like `Hz` as hν, so a pair known to
+/// another category as well, e.g. `Hz` and `rad/s`, is converted there with its exact factor.
+pub(crate) fn preferred_category(knows: impl Fn(Category) -> bool) -> Option<Category> {
+    let mut categories = Category::ALL.into_iter().filter(|&c| knows(c));
+    let category = categories.next()?;
+    Some(match category {
+        Category::Energy => categories.next().unwrap_or(category),
+        _ => category,
+    })
+}
+
+/// Returns the category to convert between two units in along with the factor of the SI prefix
+/// and the unit each of them is converted as, see [`convert`].
+fn resolve_units<'a>(
+    from: &'a str,
+    to: &'a str,
+) -> Result<(Category, Reading<'a>, Reading<'a>), ConversionError> {
+    for from_reading in readings(from) {
+        for to_reading in readings(to) {
+            if let Some(category) = shared_category(from_reading.1, to_reading.1) {
+                return Ok((category, from_reading, to_reading));
+            }
+        }
+    }
+    let (from, to) = (unprefixed(from).1, unprefixed(to).1);
+    let from_category =
+        Category::of(from).ok_or_else(|| ConversionError::UnknownUnit(from.to_string()))?;
+    let to_category =
+        Category::of(to).ok_or_else(|| ConversionError::UnknownUnit(to.to_string()))?;
+    Err(ConversionError::DimensionMismatch {
+        from_category,
+        to_category,
+    })
+}
+
+/// Returns the category to convert between two units in, see [`convert`].
+pub(crate) fn common_category(from: &str, to: &str) -> Result<Category, ConversionError> {
+    resolve_units(from, to).map(|(category, _, _)| category)
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::codata::expected;
+
+    #[test]
+    fn frequencies_are_not_converted_as_photon_energies() {
+        assert_eq!(convert(1.0, "Hz", "rad/s"), Ok(core::f64::consts::TAU));
+        assert_eq!(convert(1.0, "THz", "Hz"), Ok(1e12));
+        assert_eq!(
+            conversion_factor("GHz", "Hz"),
+            crate::frequency::try_convert(1.0, "GHz", "Hz")
+        );
+        assert_relative_eq!(
+            convert(1.0, "THz", "eV").unwrap(),
+            crate::energy::PLANCK_CONSTANT_EV * 1e12
+        );
+    }
+
+    #[test]
+    fn prefixed_frequencies_convert_to_photon_energies() {
+        let planck = crate::energy::PLANCK_CONSTANT_EV;
+        assert_relative_eq!(convert(1.0, "kHz", "eV").unwrap(), planck * 1e3);
+        assert_relative_eq!(convert(1.0, "MHz", "eV").unwrap(), planck * 1e6);
+        assert_relative_eq!(convert(planck * 1e6, "eV", "MHz").unwrap(), 1.0);
+        assert_eq!(convert(1.0, "kHz", "Hz"), Ok(1e3));
+        assert_eq!(
+            convert(1.0, "min", "m"),
+            Err(ConversionError::DimensionMismatch {
+                from_category: Category::Time,
+                to_category: Category::Length,
+            })
+        );
+    }
+
+    #[test]
+    fn resolves_si_prefixed_units() {
+        assert_eq!(convert(1.0, "km", "m"), Ok(1e3));
+        assert_eq!(convert(1.0, "Gm", "m"), Ok(1e9));
+        assert_relative_eq!(
+            convert(12.398_42, "keV", "ang").unwrap(),
+            1.0,
+            max_relative = 1e-6
+        );
+        assert_relative_eq!(convert(1.0, "m", "dam").unwrap(), 0.1);
+        assert_eq!(
+            convert(1.0, "km", "eV"),
+            Err(ConversionError::DimensionMismatch {
+                from_category: Category::Length,
+                to_category: Category::Energy,
+            })
+        );
+        assert_eq!(
+            convert(1.0, "xm", "m"),
+            Err(ConversionError::UnknownUnit("xm".to_string()))
+        );
+    }
+
+    #[test]
+    fn routes_to_the_right_category() {
+        assert_relative_eq!(
+            convert(1.0, "bohr", "ang").unwrap(),
+            expected::BOHR_RADIUS_IN_ANGSTROM
+        );
+        assert_relative_eq!(convert(2.0, "eV", "rcm").unwrap(), 16_131.087_874);
+        assert_relative_eq!(convert(1.0, "nm", "ang").unwrap(), 10.0);
+        assert_relative_eq!(convert(0.0, "degC", "K").unwrap(), 273.15);
+        assert_relative_eq!(convert(0.0, "psig", "atm").unwrap(), 1.0);
+        assert_relative_eq!(
+            convert(1240.0, "nm", "eV").unwrap(),
+            1.0,
+            max_relative = 1e-3
+        );
+    }
+
+    #[test]
+    fn conversion_factors() {
+        assert_relative_eq!(
+            conversion_factor("bohr", "ang").unwrap(),
+            expected::BOHR_RADIUS_IN_ANGSTROM
+        );
+        assert_relative_eq!(conversion_factor("K", "degR").unwrap(), 1.8);
+        assert_relative_eq!(conversion_factor("nm", "nm").unwrap(), 1.0);
+        assert_eq!(
+            conversion_factor("degC", "K"),
+            Err(ConversionError::AffineUnit("degC".to_string()))
+        );
+        assert_eq!(
+            conversion_factor("psig", "Pa"),
+            Err(ConversionError::AffineUnit("psig".to_string()))
+        );
+        assert_eq!(
+            conversion_factor("eV", "nm"),
+            Err(ConversionError::ReciprocalUnit("nm".to_string()))
+        );
+        assert_eq!(
+            conversion_factor("eV", "bohr"),
+            Err(ConversionError::DimensionMismatch {
+                from_category: Category::Energy,
+                to_category: Category::Length,
+            })
+        );
+    }
+
+    #[test]
+    fn cached_factors_give_identical_results() {
+        for (from, to) in [("psi", "GPa"), ("kn", "km/h"), ("lb", "kg")] {
+            let factor = conversion_factor(from, to).unwrap();
+            let uncached = 2.5 * factor;
+            assert_eq!(convert(2.5, from, to), Ok(uncached));
+            assert_eq!(convert(2.5, from, to), Ok(uncached));
+            #[cfg(feature = "std")]
+            assert_eq!(
+                cache::cached_factor(from, to),
+                Some(factor),
+                "{} {}",
+                from,
+                to
+            );
+        }
+        assert_relative_eq!(convert(25.0, "degC", "K").unwrap(), 298.15);
+        assert_relative_eq!(
+            convert(1240.0, "nm", "eV").unwrap(),
+            1.0,
+            max_relative = 1e-3
+        );
+        assert_eq!(cache::cached_factor("degC", "K"), None);
+        assert_eq!(cache::cached_factor("nm", "eV"), None);
+    }
+
+    #[test]
+    fn batch_matches_element_wise_conversion() {
+        let values = [0.0, 1.0, -2.5, 1e3, 6.022e23];
+        for (from, to) in [
+            ("bohr", "ang"),
+            ("degC", "degF"),
+            ("nm", "eV"),
+            ("kJ/mol", "rcm"),
+        ] {
+            let mut out = [0.0; 5];
+            convert_slice(&values, from, to, &mut out).unwrap();
+            for (v, o) in values.iter().zip(out) {
+                assert_relative_eq!(o, convert(*v, from, to).unwrap(), max_relative = 1e-12);
+            }
+        }
+        let mut in_place = values;
+        convert_slice_mut(&mut in_place, "m", "nm").unwrap();
+        assert_relative_eq!(in_place[1], 1e9);
+        assert_eq!(
+            convert_slice_mut(&mut in_place, "eV", "bohr"),
+            Err(ConversionError::DimensionMismatch {
+                from_category: Category::Energy,
+                to_category: Category::Length,
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_mismatched_and_unknown_units() {
+        assert_eq!(
+            convert(1.0, "eV", "bohr"),
+            Err(ConversionError::DimensionMismatch {
+                from_category: Category::Energy,
+                to_category: Category::Length,
+            })
+        );
+        assert_eq!(
+            convert(1.0, "parsnip", "bohr"),
+            Err(ConversionError::UnknownUnit("parsnip".to_string()))
+        );
+    }
+
+    #[test]
+    fn mismatch_reports_both_categories() {
+        for (from, to, from_category, to_category) in [
+            ("kg", "s", Category::Mass, Category::Time),
+            ("kHz", "bohr", Category::Frequency, Category::Length),
+            ("Pa", "N", Category::Pressure, Category::Force),
+            ("degC", "m/s", Category::Temperature, Category::Speed),
+            (
+                "muB",
+                "T",
+                Category::MagneticMoment,
+                Category::MagneticField,
+            ),
+            ("deg", "cm²", Category::Angle, Category::Area),
+        ] {
+            let expected = Err(ConversionError::DimensionMismatch {
+                from_category,
+                to_category,
+            });
+            assert_eq!(convert(1.0, from, to), expected);
+            assert_eq!(conversion_factor(from, to), expected);
+        }
+    }
+
+    #[test]
+    fn convert_macro() {
+        assert_relative_eq!(
+            crate::convert!(1.0, "bohr" => "ang").unwrap(),
+            expected::BOHR_RADIUS_IN_ANGSTROM
+        );
+        assert_relative_eq!(crate::convert!(2.0 "eV" => "rcm").unwrap(), 16_131.087_874);
+        let (value, unit) = (0.5, "hartree");
+        assert_eq!(
+            crate::convert!(value, unit => "eV"),
+            convert(0.5, "hartree", "eV")
+        );
+        assert_eq!(
+            crate::convert!(1.0, "parsnip" => "bohr"),
+            Err(ConversionError::UnknownUnit("parsnip".to_string()))
+        );
+    }
+}
@@ -0,0 +1,50 @@
+//! This module offers electric field strength unit conversion functions in double precision,
+//! i.e. f64. The atomic unit of the electric field Eₕ/(e·a₀) is derived from the Hartree energy
+//! and the Bohr radius: in eV the elementary charge cancels, leaving Eₕ[eV]/a₀[m] in V/m.
+
+use crate::energy::HARTREE_EV_CONVERSION_FACTOR;
+use crate::length::BOHR_RADIUS_TO_METRES;
+use crate::table::conversion_table;
+
+const ATOMIC_UNIT_TO_VOLTS_PER_METRE: f64 = HARTREE_EV_CONVERSION_FACTOR / BOHR_RADIUS_TO_METRES;
+const VOLTS_PER_CENTIMETRE_TO_VOLTS_PER_METRE: f64 = 100.0;
+
+conversion_table!(
+    kind = "electric field",
+    category = ElectricField,
+    a_kind = "an electric field",
+    base = "volts per metre",
+    /// Returns the size of the electric field unit in volts per metre.
+    factor = vpm_per,
+    to_base = to_vpm,
+    from_base = from_vpm,
+    to_map = convert_2_vpm_from,
+    from_map = convert_from_vpm_2,
+    units = {
+        "V/m" => 1.0,
+        "V/cm" => VOLTS_PER_CENTIMETRE_TO_VOLTS_PER_METRE,
+        "au" => ATOMIC_UNIT_TO_VOLTS_PER_METRE,
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn atomic_unit_of_field() {
+        assert_relative_eq!(
+            try_convert(1.0, "au", "V/m").unwrap(),
+            5.142_206_747e11,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(try_convert(1.0, "V/cm", "V/m").unwrap(), 100.0);
+        assert_relative_eq!(
+            try_convert(1e9, "V/m", "au").unwrap(),
+            1.944_690_4e-3,
+            max_relative = 1e-7
+        );
+    }
+}
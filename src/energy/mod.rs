@@ -1,42 +1,567 @@
-use lazy_static::lazy_static;
-use std::collections::HashMap;
+//! This module offers energy unit conversion functions. The functions are generic over the float
+//! type, so they can run in single or double precision; the conversion maps hold the f64 variants.
+//! Conversion factors are taken from [here](https://physics.nist.gov/cuu/Constants/energy.html)
 
-/// This module offers energy unit conversion functions in double precision, i.e. f64.
-/// Conversion factors are taken from [here](https://physics.nist.gov/cuu/Constants/energy.html)
+use alloc::string::ToString;
 
-const EV_REC_CENTIMETRES_CONVERSION_FACTOR: f64 = 8_065.543_937;
-const REC_CENTIMETRES_EV_CONVERSION_FACTOR: f64 = 1.239_841_984_332 * 10e-8;
+use num_traits::Float;
 
-type Callback = fn(f64) -> f64;
+use crate::amount::AVOGADRO_CONSTANT;
+use crate::category::{convert_within, Callback, UnitCategory};
+use crate::codata;
+use crate::collections::Map;
+use crate::float::cast;
+use crate::length::ANGSTROEM;
+use crate::lookup::get_converter;
+use crate::prefix::normalize_micro;
+use crate::sync::OnceLock;
+use crate::ConversionError;
 
-fn unity(energy_in_arb: f64) -> f64 {
-    energy_in_arb
+pub const EV_REC_CENTIMETRES_CONVERSION_FACTOR: f64 = 8_065.543_937;
+pub const REC_CENTIMETRES_EV_CONVERSION_FACTOR: f64 = 1.239_841_984e-4;
+pub const HARTREE_EV_CONVERSION_FACTOR: f64 = codata::HARTREE_EV_CONVERSION_FACTOR;
+pub const HARTREE_REC_CENTIMETRES_CONVERSION_FACTOR: f64 =
+    codata::HARTREE_REC_CENTIMETRES_CONVERSION_FACTOR;
+/// One Rydberg is exactly half a Hartree.
+pub const RYDBERG_HARTREE_CONVERSION_FACTOR: f64 = 0.5;
+pub const RYDBERG_EV_CONVERSION_FACTOR: f64 =
+    HARTREE_EV_CONVERSION_FACTOR * RYDBERG_HARTREE_CONVERSION_FACTOR;
+pub const EV_JOULE_CONVERSION_FACTOR: f64 = 1.602_176_634e-19;
+pub const KCAL_JOULE_CONVERSION_FACTOR: f64 = 4184.0;
+pub const BOLTZMANN_CONSTANT_EV: f64 = 8.617_333_262e-5;
+pub const PLANCK_CONSTANT_EV: f64 = 4.135_667_696e-15;
+pub const REDUCED_PLANCK_CONSTANT_EV: f64 = 6.582_119_569e-16;
+pub const SPEED_OF_LIGHT: f64 = 299_792_458.0;
+const CENTIMETRES_PER_METRE: f64 = 100.0;
+/// One kilokayser is 1000 cm⁻¹.
+const KILOKAYSER_REC_CENTIMETRES: f64 = 1e3;
+const NANOMETRES_PER_METRE: f64 = 1e9;
+const NANOMETRES_PER_CENTIMETRE: f64 = 1e7;
+const TERA: f64 = 1e12;
+const GIGA: f64 = 1e9;
+const KILO: f64 = 1e3;
+const MILLI: f64 = 1e-3;
+const MIKRO: f64 = 1e-6;
+
+fn unity<T: Float>(energy_in_arb: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(energy_in_arb)
+}
+
+/// Converts an energy in electronvolt to wavenumbers in cm⁻¹.
+pub fn ev_2_rcm<T: Float>(energy_in_ev: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(energy_in_ev * cast(EV_REC_CENTIMETRES_CONVERSION_FACTOR))
+}
+
+/// Converts a wavenumber in cm⁻¹ to an energy in electronvolt.
+pub fn rcm_2_ev<T: Float>(energy_in_rcm: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(energy_in_rcm * cast(REC_CENTIMETRES_EV_CONVERSION_FACTOR))
+}
+
+/// Converts an energy in Hartree to electronvolt.
+pub fn hartree_2_ev<T: Float>(energy_in_hartree: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(energy_in_hartree * cast(HARTREE_EV_CONVERSION_FACTOR))
+}
+
+/// Converts an energy in electronvolt to Hartree.
+pub fn ev_2_hartree<T: Float>(energy_in_ev: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(energy_in_ev / cast(HARTREE_EV_CONVERSION_FACTOR))
+}
+
+/// Converts an energy in Hartree to wavenumbers in cm⁻¹.
+pub fn hartree_2_rcm<T: Float>(energy_in_hartree: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(energy_in_hartree * cast(HARTREE_REC_CENTIMETRES_CONVERSION_FACTOR))
+}
+
+/// Converts a wavenumber in cm⁻¹ to an energy in Hartree.
+pub fn rcm_2_hartree<T: Float>(energy_in_rcm: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(energy_in_rcm / cast(HARTREE_REC_CENTIMETRES_CONVERSION_FACTOR))
+}
+
+/// Converts a wavenumber in cm⁻¹ to m⁻¹.
+pub fn rcm_2_rm<T: Float>(energy_in_rcm: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(energy_in_rcm * cast(CENTIMETRES_PER_METRE))
+}
+
+/// Converts a wavenumber in m⁻¹ to cm⁻¹.
+pub fn rm_2_rcm<T: Float>(energy_in_rm: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(energy_in_rm / cast(CENTIMETRES_PER_METRE))
+}
+
+/// Converts a wavenumber in kK to cm⁻¹.
+pub fn kk_2_rcm<T: Float>(energy_in_kk: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(energy_in_kk * cast(KILOKAYSER_REC_CENTIMETRES))
+}
+
+/// Converts a wavenumber in m⁻¹ to an energy in electronvolt.
+pub fn rm_2_ev<T: Float>(energy_in_rm: T, _prefix: &str) -> Result<T, ConversionError> {
+    rcm_2_ev(rm_2_rcm(energy_in_rm, "")?, "")
+}
+
+/// Converts an energy in electronvolt to a wavenumber in m⁻¹.
+pub fn ev_2_rm<T: Float>(energy_in_ev: T, _prefix: &str) -> Result<T, ConversionError> {
+    rcm_2_rm(ev_2_rcm(energy_in_ev, "")?, "")
+}
+
+/// Converts a wavenumber in kK to an energy in electronvolt.
+pub fn kk_2_ev<T: Float>(energy_in_kk: T, _prefix: &str) -> Result<T, ConversionError> {
+    rcm_2_ev(kk_2_rcm(energy_in_kk, "")?, "")
+}
+
+/// Converts an energy in electronvolt to a wavenumber in kK.
+pub fn ev_2_kk<T: Float>(energy_in_ev: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(ev_2_rcm(energy_in_ev, "")? / cast(KILOKAYSER_REC_CENTIMETRES))
+}
+
+/// Returns the size of the wavenumber unit in cm⁻¹.
+/// # Arguments
+///  * `prefix` - can be one of [`rcm`, `rm`, `kK`] or empty for cm⁻¹. `kK` is the kilokayser,
+///    1000 cm⁻¹.
+///
+fn wavenumber_prefix_factor<T: Float>(prefix: &str) -> Result<T, ConversionError> {
+    match prefix {
+        "" | "rcm" => Ok(T::one()),
+        "rm" => Ok(T::one() / cast(CENTIMETRES_PER_METRE)),
+        "kK" => Ok(cast(KILOKAYSER_REC_CENTIMETRES)),
+        _ => Err(ConversionError::UnknownPrefix(prefix.to_string())),
+    }
+}
+
+/// Converts a wavenumber in cm⁻¹, m⁻¹ or kK to cm⁻¹.
+/// # Arguments
+///  * `wavenumber` - value to convert
+///  * `prefix` - can be one of [`rcm`, `rm`, `kK`]. Gives the unit of the value.
+///
+pub fn wavenumber_2_rcm<T: Float>(wavenumber: T, prefix: &str) -> Result<T, ConversionError> {
+    Ok(wavenumber * wavenumber_prefix_factor(prefix)?)
+}
+
+/// Converts a wavenumber in cm⁻¹, m⁻¹ or kK to an energy in electronvolt.
+/// # Arguments
+///  * `wavenumber` - value to convert
+///  * `prefix` - can be one of [`rcm`, `rm`, `kK`]. Gives the unit of the value.
+///
+pub fn wavenumber_2_ev<T: Float>(wavenumber: T, prefix: &str) -> Result<T, ConversionError> {
+    rcm_2_ev(wavenumber_2_rcm(wavenumber, prefix)?, "")
+}
+
+/// Converts an energy in electronvolt to a wavenumber in cm⁻¹, m⁻¹ or kK.
+/// # Arguments
+///  * `energy_in_ev` - value to convert
+///  * `prefix` - can be one of [`rcm`, `rm`, `kK`]. Gives the unit of the result.
+///
+pub fn ev_2_wavenumber<T: Float>(energy_in_ev: T, prefix: &str) -> Result<T, ConversionError> {
+    Ok(ev_2_rcm(energy_in_ev, "")? / wavenumber_prefix_factor(prefix)?)
 }
 
-fn ev_2_rcm(energy_in_ev: f64) -> f64 {
+/// Converts electronvolt to cm⁻¹ without a map lookup, so it can be used in const contexts.
+pub const fn ev_2_rcm_const(energy_in_ev: f64) -> f64 {
     energy_in_ev * EV_REC_CENTIMETRES_CONVERSION_FACTOR
 }
 
-fn rcm_2_ev(energy_in_rcm: f64) -> f64 {
+/// Converts cm⁻¹ to electronvolt without a map lookup, so it can be used in const contexts.
+pub const fn rcm_2_ev_const(energy_in_rcm: f64) -> f64 {
     energy_in_rcm * REC_CENTIMETRES_EV_CONVERSION_FACTOR
 }
 
-lazy_static! {
-    pub static ref CONVERT_2_RCM_FROM: HashMap<&'static str, Callback> = {
-        let mut t = HashMap::new();
+/// Converts Hartree to electronvolt without a map lookup, so it can be used in const contexts.
+pub const fn hartree_2_ev_const(energy_in_hartree: f64) -> f64 {
+    energy_in_hartree * HARTREE_EV_CONVERSION_FACTOR
+}
+
+/// Converts electronvolt to Hartree without a map lookup, so it can be used in const contexts.
+pub const fn ev_2_hartree_const(energy_in_ev: f64) -> f64 {
+    energy_in_ev / HARTREE_EV_CONVERSION_FACTOR
+}
+
+/// Converts Hartree to cm⁻¹ without a map lookup, so it can be used in const contexts.
+pub const fn hartree_2_rcm_const(energy_in_hartree: f64) -> f64 {
+    energy_in_hartree * HARTREE_REC_CENTIMETRES_CONVERSION_FACTOR
+}
+
+/// Converts cm⁻¹ to Hartree without a map lookup, so it can be used in const contexts.
+pub const fn rcm_2_hartree_const(energy_in_rcm: f64) -> f64 {
+    energy_in_rcm / HARTREE_REC_CENTIMETRES_CONVERSION_FACTOR
+}
+
+/// Converts an energy in Rydberg to electronvolt.
+pub fn ry_2_ev<T: Float>(energy_in_ry: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(energy_in_ry * cast(RYDBERG_EV_CONVERSION_FACTOR))
+}
+
+/// Converts an energy in electronvolt to Rydberg.
+pub fn ev_2_ry<T: Float>(energy_in_ev: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(energy_in_ev / cast(RYDBERG_EV_CONVERSION_FACTOR))
+}
+
+/// Converts an energy in Rydberg to Hartree.
+pub fn ry_2_hartree<T: Float>(energy_in_ry: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(energy_in_ry * cast(RYDBERG_HARTREE_CONVERSION_FACTOR))
+}
+
+/// Returns the factor scaling a value in a prefixed joule unit to joule.
+/// # Arguments
+///  * `prefix` - can be one of [`µJ`, `mJ`, `J`, `joule`, `kJ`] or empty for joule. The micro
+///    prefix may also be spelled `μ` or `u`.
+///
+fn joule_prefix_factor<T: Float>(prefix: &str) -> Result<T, ConversionError> {
+    match normalize_micro(prefix).as_ref() {
+        "µJ" => Ok(cast(MIKRO)),
+        "mJ" => Ok(cast(MILLI)),
+        "" | "J" | "joule" => Ok(T::one()),
+        "kJ" => Ok(cast(KILO)),
+        _ => Err(ConversionError::UnknownPrefix(prefix.to_string())),
+    }
+}
+
+/// Converts an energy in (prefixed) joule to electronvolt.
+/// # Arguments
+///  * `energy_in_j` - value to convert
+///  * `prefix` - can be one of [`µJ`, `mJ`, `J`, `joule`, `kJ`]. Gives the unit of the value.
+///
+pub fn j_2_ev<T: Float>(energy_in_j: T, prefix: &str) -> Result<T, ConversionError> {
+    Ok(energy_in_j * joule_prefix_factor(prefix)? / cast(EV_JOULE_CONVERSION_FACTOR))
+}
+
+/// Converts an energy in electronvolt to (prefixed) joule.
+/// # Arguments
+///  * `energy_in_ev` - value to convert
+///  * `prefix` - can be one of [`µJ`, `mJ`, `J`, `joule`, `kJ`]. Gives the unit of the result.
+///
+pub fn ev_2_j<T: Float>(energy_in_ev: T, prefix: &str) -> Result<T, ConversionError> {
+    Ok(energy_in_ev * cast(EV_JOULE_CONVERSION_FACTOR) / joule_prefix_factor(prefix)?)
+}
+
+/// Converts an energy in (prefixed) joule to wavenumbers in cm⁻¹.
+pub fn j_2_rcm<T: Float>(energy_in_j: T, prefix: &str) -> Result<T, ConversionError> {
+    ev_2_rcm(j_2_ev(energy_in_j, prefix)?, "")
+}
+
+/// Converts an energy in kcal to electronvolt.
+pub fn kcal_2_ev<T: Float>(energy_in_kcal: T, _prefix: &str) -> Result<T, ConversionError> {
+    j_2_ev(energy_in_kcal * cast(KCAL_JOULE_CONVERSION_FACTOR), "J")
+}
+
+/// Converts an energy in electronvolt to kcal.
+pub fn ev_2_kcal<T: Float>(energy_in_ev: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(ev_2_j(energy_in_ev, "J")? / cast(KCAL_JOULE_CONVERSION_FACTOR))
+}
+
+/// Converts an energy in kcal to wavenumbers in cm⁻¹.
+pub fn kcal_2_rcm<T: Float>(energy_in_kcal: T, prefix: &str) -> Result<T, ConversionError> {
+    ev_2_rcm(kcal_2_ev(energy_in_kcal, prefix)?, "")
+}
+
+/// Converts a molar energy in kJ/mol to electronvolt per particle.
+pub fn kjmol_2_ev<T: Float>(energy_in_kjmol: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(j_2_ev(energy_in_kjmol, "kJ")? / cast(AVOGADRO_CONSTANT))
+}
+
+/// Converts an energy in electronvolt per particle to kJ/mol.
+pub fn ev_2_kjmol<T: Float>(energy_in_ev: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(ev_2_j(energy_in_ev, "kJ")? * cast(AVOGADRO_CONSTANT))
+}
+
+/// Converts a molar energy in kcal/mol to electronvolt per particle.
+pub fn kcalmol_2_ev<T: Float>(energy_in_kcalmol: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(kcal_2_ev(energy_in_kcalmol, "kcal")? / cast(AVOGADRO_CONSTANT))
+}
+
+/// Converts an energy in electronvolt per particle to kcal/mol.
+pub fn ev_2_kcalmol<T: Float>(energy_in_ev: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(ev_2_kcal(energy_in_ev, "kcal")? * cast(AVOGADRO_CONSTANT))
+}
+
+/// Converts a molar energy in kJ/mol to wavenumbers in cm⁻¹ per particle.
+pub fn kjmol_2_rcm<T: Float>(energy_in_kjmol: T, prefix: &str) -> Result<T, ConversionError> {
+    ev_2_rcm(kjmol_2_ev(energy_in_kjmol, prefix)?, "")
+}
+
+/// Converts a molar energy in kcal/mol to wavenumbers in cm⁻¹ per particle.
+pub fn kcalmol_2_rcm<T: Float>(energy_in_kcalmol: T, prefix: &str) -> Result<T, ConversionError> {
+    ev_2_rcm(kcalmol_2_ev(energy_in_kcalmol, prefix)?, "")
+}
+
+/// Converts a temperature in kelvin to its thermal energy equivalent k_B·T in electronvolt.
+///
+/// This is an energy-scale equivalence, not a temperature scale conversion: the input is an
+/// absolute temperature and the result is the characteristic thermal energy at that temperature.
+/// It is therefore not registered in the conversion maps; see
+/// [`crate::temperature::temperature_to_energy`] for other energy units.
+pub fn kelvin_2_ev<T: Float>(temperature_in_k: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(temperature_in_k * cast(BOLTZMANN_CONSTANT_EV))
+}
+
+/// Converts an energy in electronvolt to the temperature T at which k_B·T equals that energy.
+///
+/// Like [`kelvin_2_ev`] this is a thermal-energy equivalence, not a temperature scale conversion.
+pub fn ev_2_kelvin<T: Float>(energy_in_ev: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(energy_in_ev / cast(BOLTZMANN_CONSTANT_EV))
+}
+
+/// Converts a temperature in kelvin to its thermal energy equivalent k_B·T in cm⁻¹.
+pub fn kelvin_2_rcm<T: Float>(temperature_in_k: T, prefix: &str) -> Result<T, ConversionError> {
+    ev_2_rcm(kelvin_2_ev(temperature_in_k, prefix)?, "")
+}
+
+/// Returns the factor scaling a value in a prefixed hertz unit to hertz.
+/// # Arguments
+///  * `prefix` - can be one of [`Hz`, `GHz`, `THz`] or empty for hertz.
+///
+fn hertz_prefix_factor<T: Float>(prefix: &str) -> Result<T, ConversionError> {
+    match prefix {
+        "" | "Hz" => Ok(T::one()),
+        "GHz" => Ok(cast(GIGA)),
+        "THz" => Ok(cast(TERA)),
+        _ => Err(ConversionError::UnknownPrefix(prefix.to_string())),
+    }
+}
+
+/// Converts a photon frequency in (prefixed) hertz to its energy in electronvolt via E = h·f.
+/// # Arguments
+///  * `frequency` - value to convert
+///  * `prefix` - can be one of [`Hz`, `GHz`, `THz`]. Gives the unit of the value.
+///
+pub fn hz_2_ev<T: Float>(frequency: T, prefix: &str) -> Result<T, ConversionError> {
+    Ok(frequency * hertz_prefix_factor(prefix)? * cast(PLANCK_CONSTANT_EV))
+}
+
+/// Converts a photon energy in electronvolt to its frequency in (prefixed) hertz via f = E/h.
+/// # Arguments
+///  * `energy_in_ev` - value to convert
+///  * `prefix` - can be one of [`Hz`, `GHz`, `THz`]. Gives the unit of the result.
+///
+pub fn ev_2_hz<T: Float>(energy_in_ev: T, prefix: &str) -> Result<T, ConversionError> {
+    Ok(energy_in_ev / cast(PLANCK_CONSTANT_EV) / hertz_prefix_factor(prefix)?)
+}
+
+/// Converts a photon frequency in THz to its energy in electronvolt via E = h·f.
+pub fn thz_2_ev<T: Float>(frequency_in_thz: T, _prefix: &str) -> Result<T, ConversionError> {
+    hz_2_ev(frequency_in_thz, "THz")
+}
+
+/// Converts a photon energy in electronvolt to its frequency in THz via f = E/h.
+pub fn ev_2_thz<T: Float>(energy_in_ev: T, _prefix: &str) -> Result<T, ConversionError> {
+    ev_2_hz(energy_in_ev, "THz")
+}
+
+/// Converts an angular frequency ω in rad/s to the photon energy in electronvolt via E = ħ·ω.
+///
+/// Not to be confused with [`hz_2_ev`] for the ordinary frequency f = ω/2π, which uses h = 2πħ;
+/// the same energy has a 2π times larger angular frequency than frequency.
+pub fn omega_2_ev<T: Float>(omega: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(omega * cast(REDUCED_PLANCK_CONSTANT_EV))
+}
+
+/// Converts a photon energy in electronvolt to the angular frequency ω in rad/s via ω = E/ħ.
+/// See [`omega_2_ev`] for the difference to the frequency in Hz.
+pub fn ev_2_omega<T: Float>(energy_in_ev: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(energy_in_ev / cast(REDUCED_PLANCK_CONSTANT_EV))
+}
+
+/// Converts a frequency in (prefixed) hertz to a wavenumber in cm⁻¹ via ν̃ = f/c, with c in cm/s.
+/// # Arguments
+///  * `frequency` - value to convert
+///  * `prefix` - can be one of [`Hz`, `GHz`, `THz`]. Gives the unit of the value.
+///
+pub fn hz_2_rcm<T: Float>(frequency: T, prefix: &str) -> Result<T, ConversionError> {
+    Ok(frequency * hertz_prefix_factor(prefix)? / cast(SPEED_OF_LIGHT * CENTIMETRES_PER_METRE))
+}
+
+/// Converts a wavenumber in cm⁻¹ to a frequency in Hz via f = c·ν̃, with c in cm/s.
+pub fn rcm_2_hz<T: Float>(energy_in_rcm: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(energy_in_rcm * cast(SPEED_OF_LIGHT * CENTIMETRES_PER_METRE))
+}
+
+/// Units inversely proportional to the energy, so that they can not be converted with a single
+/// factor into the other energy units.
+pub(crate) const RECIPROCAL_UNITS: [&str; 2] = ["nm", "ang"];
+
+/// Takes the reciprocal of a wavelength-like value, scaled by `numerator`.
+/// A zero input returns positive infinity instead of relying on the sign of the zero.
+fn reciprocal<T: Float>(numerator: T, v: T) -> T {
+    if v == T::zero() {
+        return T::infinity();
+    }
+    numerator / v
+}
+
+/// Converts a photon wavelength in nm to its energy in electronvolt via E = hc/λ.
+/// This is a reciprocal relationship; a zero wavelength returns infinity.
+pub fn nm_2_ev<T: Float>(wavelength_in_nm: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(reciprocal(
+        cast(PLANCK_CONSTANT_EV * SPEED_OF_LIGHT * NANOMETRES_PER_METRE),
+        wavelength_in_nm,
+    ))
+}
+
+/// Converts a photon energy in electronvolt to its wavelength in nm via λ = hc/E.
+/// This is a reciprocal relationship; a zero energy returns infinity.
+pub fn ev_2_nm<T: Float>(energy_in_ev: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(reciprocal(
+        cast(PLANCK_CONSTANT_EV * SPEED_OF_LIGHT * NANOMETRES_PER_METRE),
+        energy_in_ev,
+    ))
+}
+
+/// Converts a photon wavelength in Å to its energy in electronvolt via E = hc/λ, about
+/// 12.398 keV for 1 Å. This is a reciprocal relationship; a zero wavelength returns infinity.
+pub fn ang_2_ev<T: Float>(wavelength_in_ang: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(reciprocal(
+        cast(PLANCK_CONSTANT_EV * SPEED_OF_LIGHT / ANGSTROEM),
+        wavelength_in_ang,
+    ))
+}
+
+/// Converts a photon energy in electronvolt to its wavelength in Å via λ = hc/E.
+/// This is a reciprocal relationship; a zero energy returns infinity.
+pub fn ev_2_ang<T: Float>(energy_in_ev: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(reciprocal(
+        cast(PLANCK_CONSTANT_EV * SPEED_OF_LIGHT / ANGSTROEM),
+        energy_in_ev,
+    ))
+}
+
+/// Converts a wavelength in nm to a wavenumber in cm⁻¹ via ν̃ = 1/λ.
+/// This is a reciprocal relationship; a zero wavelength returns infinity.
+pub fn nm_2_rcm<T: Float>(wavelength_in_nm: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(reciprocal(
+        cast(NANOMETRES_PER_CENTIMETRE),
+        wavelength_in_nm,
+    ))
+}
+
+/// Converts a wavenumber in cm⁻¹ to a wavelength in nm via λ = 1/ν̃.
+/// This is a reciprocal relationship; a zero wavenumber returns infinity.
+pub fn rcm_2_nm<T: Float>(energy_in_rcm: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(reciprocal(cast(NANOMETRES_PER_CENTIMETRE), energy_in_rcm))
+}
+
+/// Returns the Raman shift in cm⁻¹ of light scattered at `scattered_in_nm` from an excitation at
+/// `excitation_in_nm`, i.e. the difference 1/λ₀ − 1/λ of the wavenumbers. Stokes lines, which are
+/// red-shifted, give a positive shift and anti-Stokes lines a negative one.
+pub fn raman_shift(excitation_in_nm: f64, scattered_in_nm: f64) -> Result<f64, ConversionError> {
+    Ok(nm_2_rcm(excitation_in_nm, "")? - nm_2_rcm(scattered_in_nm, "")?)
+}
+
+pub fn convert_2_rcm_from() -> &'static Map<&'static str, Callback> {
+    static MAP: OnceLock<Map<&'static str, Callback>> = OnceLock::new();
+    MAP.get_or_init(|| {
+        let mut t = Map::new();
         t.insert("rcm", unity as Callback);
+        t.insert("rm", rm_2_rcm as Callback);
+        t.insert("kK", kk_2_rcm as Callback);
         t.insert("eV", ev_2_rcm as Callback);
+        t.insert("hartree", hartree_2_rcm as Callback);
+        t.insert("J", j_2_rcm as Callback);
+        t.insert("joule", j_2_rcm as Callback);
+        t.insert("kJ", j_2_rcm as Callback);
+        t.insert("mJ", j_2_rcm as Callback);
+        t.insert("µJ", j_2_rcm as Callback);
+        t.insert("μJ", j_2_rcm as Callback);
+        t.insert("uJ", j_2_rcm as Callback);
+        t.insert("kcal", kcal_2_rcm as Callback);
+        t.insert("kJ/mol", kjmol_2_rcm as Callback);
+        t.insert("kcal/mol", kcalmol_2_rcm as Callback);
+        t.insert("Hz", hz_2_rcm as Callback);
+        t.insert("GHz", hz_2_rcm as Callback);
+        t.insert("THz", hz_2_rcm as Callback);
+        t.insert("nm", nm_2_rcm as Callback);
         t
-    };
+    })
 }
 
-lazy_static! {
-    pub static ref CONVERT_2_EV_FROM: HashMap<&'static str, Callback> = {
-        let mut t = HashMap::new();
+pub fn convert_2_ev_from() -> &'static Map<&'static str, Callback> {
+    static MAP: OnceLock<Map<&'static str, Callback>> = OnceLock::new();
+    MAP.get_or_init(|| {
+        let mut t = Map::new();
         t.insert("eV", unity as Callback);
         t.insert("rcm", rcm_2_ev as Callback);
+        t.insert("rm", rm_2_ev as Callback);
+        t.insert("kK", kk_2_ev as Callback);
+        t.insert("hartree", hartree_2_ev as Callback);
+        t.insert("J", j_2_ev as Callback);
+        t.insert("joule", j_2_ev as Callback);
+        t.insert("kJ", j_2_ev as Callback);
+        t.insert("mJ", j_2_ev as Callback);
+        t.insert("µJ", j_2_ev as Callback);
+        t.insert("μJ", j_2_ev as Callback);
+        t.insert("uJ", j_2_ev as Callback);
+        t.insert("kcal", kcal_2_ev as Callback);
+        t.insert("kJ/mol", kjmol_2_ev as Callback);
+        t.insert("kcal/mol", kcalmol_2_ev as Callback);
+        t.insert("Hz", hz_2_ev as Callback);
+        t.insert("GHz", hz_2_ev as Callback);
+        t.insert("THz", hz_2_ev as Callback);
+        t.insert("rad/s", omega_2_ev as Callback);
+        t.insert("nm", nm_2_ev as Callback);
+        t.insert("ang", ang_2_ev as Callback);
+        t.insert("Ry", ry_2_ev as Callback);
         t
-    };
+    })
+}
+
+/// Written out rather than derived from the forward map, as the factors of both directions are
+/// tabulated separately and `nm` and `ang` are reciprocal.
+pub fn convert_from_ev_2() -> &'static Map<&'static str, Callback> {
+    static MAP: OnceLock<Map<&'static str, Callback>> = OnceLock::new();
+    MAP.get_or_init(|| {
+        let mut t = Map::new();
+        t.insert("eV", unity as Callback);
+        t.insert("rcm", ev_2_rcm as Callback);
+        t.insert("rm", ev_2_rm as Callback);
+        t.insert("kK", ev_2_kk as Callback);
+        t.insert("hartree", ev_2_hartree as Callback);
+        t.insert("J", ev_2_j as Callback);
+        t.insert("joule", ev_2_j as Callback);
+        t.insert("kJ", ev_2_j as Callback);
+        t.insert("mJ", ev_2_j as Callback);
+        t.insert("µJ", ev_2_j as Callback);
+        t.insert("μJ", ev_2_j as Callback);
+        t.insert("uJ", ev_2_j as Callback);
+        t.insert("kcal", ev_2_kcal as Callback);
+        t.insert("kJ/mol", ev_2_kjmol as Callback);
+        t.insert("kcal/mol", ev_2_kcalmol as Callback);
+        t.insert("Hz", ev_2_hz as Callback);
+        t.insert("GHz", ev_2_hz as Callback);
+        t.insert("THz", ev_2_hz as Callback);
+        t.insert("rad/s", ev_2_omega as Callback);
+        t.insert("nm", ev_2_nm as Callback);
+        t.insert("ang", ev_2_ang as Callback);
+        t.insert("Ry", ev_2_ry as Callback);
+        t
+    })
+}
+
+pub fn convert_2_hartree_from() -> &'static Map<&'static str, Callback> {
+    static MAP: OnceLock<Map<&'static str, Callback>> = OnceLock::new();
+    MAP.get_or_init(|| {
+        let mut t = Map::new();
+        t.insert("hartree", unity as Callback);
+        t.insert("eV", ev_2_hartree as Callback);
+        t.insert("rcm", rcm_2_hartree as Callback);
+        t.insert("Ry", ry_2_hartree as Callback);
+        t
+    })
+}
+
+/// The energy category, whose units are converted through eV.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Energy;
+
+impl UnitCategory for Energy {
+    fn to_base(value: f64, unit: &str) -> Result<f64, ConversionError> {
+        get_converter(convert_2_ev_from(), unit)?(value, unit)
+    }
+
+    fn from_base(value: f64, unit: &str) -> Result<f64, ConversionError> {
+        get_converter(convert_from_ev_2(), unit)?(value, unit)
+    }
+}
+
+/// Converts `value` from the energy unit `from` to the energy unit `to` by going through eV.
+/// Returns `UnknownUnit` if either unit is not registered instead of panicking.
+pub fn try_convert(value: f64, from: &str, to: &str) -> Result<f64, ConversionError> {
+    convert_within::<Energy>(value, from, to)
 }
 
 #[cfg(test)]
@@ -49,11 +574,256 @@ mod tests {
     fn it_works() {
         assert_eq!(
             2.0_f64 * EV_REC_CENTIMETRES_CONVERSION_FACTOR,
-            CONVERT_2_RCM_FROM["eV"](2.0_f64)
+            convert_2_rcm_from()["eV"](2.0_f64, "").unwrap()
         );
         assert_relative_eq!(
-            7.439_051_905_992_01 * 10e-5,
-            CONVERT_2_EV_FROM["rcm"](6000.0),
+            0.743_905_190_4,
+            convert_2_ev_from()["rcm"](6000.0, "").unwrap()
         )
     }
+
+    #[test]
+    fn rcm_ev_round_trip() {
+        for x in [1e-3_f64, 1.0, 6000.0, 1e6] {
+            let back = rcm_2_ev(ev_2_rcm(x, "").unwrap(), "").unwrap();
+            assert_relative_eq!(x, back, max_relative = 1e-9);
+        }
+    }
+
+    #[test]
+    fn raman_shifts() {
+        assert_relative_eq!(
+            raman_shift(532.0, 545.0).unwrap(),
+            448.37,
+            max_relative = 1e-4
+        );
+        assert_relative_eq!(
+            raman_shift(532.0, 519.6).unwrap(),
+            -raman_shift(519.6, 532.0).unwrap()
+        );
+        assert!(raman_shift(532.0, 519.6).unwrap() < 0.0);
+        assert_eq!(raman_shift(532.0, 532.0), Ok(0.0));
+    }
+
+    #[test]
+    fn reciprocal_metres_and_kilokayser() {
+        assert_eq!(rcm_2_rm(1.0, "").unwrap(), 100.0);
+        assert_eq!(rm_2_rcm(100.0, "").unwrap(), 1.0);
+        assert_relative_eq!(
+            try_convert(100.0, "rm", "eV").unwrap(),
+            rcm_2_ev(1.0, "").unwrap()
+        );
+        assert_relative_eq!(try_convert(1.0, "eV", "rm").unwrap(), 806_554.393_7);
+        // Through eV, so only as exact as the tabulated eV and cm⁻¹ factors are inverse.
+        assert_relative_eq!(
+            try_convert(1.0, "kK", "rcm").unwrap(),
+            1_000.0,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(convert_2_rcm_from()["kK"](2.0, "kK").unwrap(), 2_000.0);
+        assert_relative_eq!(convert_2_rcm_from()["rm"](2.0, "rm").unwrap(), 0.02);
+        // The fixed keys ignore the prefix argument like every other unit of the maps.
+        let rcm_in_ev = rcm_2_ev(1.0, "").unwrap();
+        assert_relative_eq!(convert_2_ev_from()["rm"](100.0, "").unwrap(), rcm_in_ev);
+        assert_relative_eq!(convert_2_ev_from()["rm"](100.0, "rm").unwrap(), rcm_in_ev);
+        assert_relative_eq!(convert_2_ev_from()["kK"](1.0, "").unwrap(), 1e3 * rcm_in_ev);
+        assert_relative_eq!(convert_2_rcm_from()["kK"](2.0, "").unwrap(), 2_000.0);
+        let ev_in_rcm = ev_2_rcm(1.0, "").unwrap();
+        assert_relative_eq!(
+            convert_from_ev_2()["rm"](1.0, "").unwrap(),
+            100.0 * ev_in_rcm
+        );
+        assert_relative_eq!(
+            convert_from_ev_2()["kK"](1.0, "").unwrap(),
+            1e-3 * ev_in_rcm
+        );
+    }
+
+    #[test]
+    fn angular_frequency_and_frequency() {
+        let omega = try_convert(1.0, "eV", "rad/s").unwrap();
+        let f = try_convert(1.0, "eV", "Hz").unwrap();
+        assert_relative_eq!(omega, 1.519_267_447e15, max_relative = 1e-9);
+        assert_relative_eq!(f, 2.417_989_242e14, max_relative = 1e-9);
+        assert_relative_eq!(omega / f, core::f64::consts::TAU, max_relative = 1e-9);
+        assert_relative_eq!(try_convert(omega, "rad/s", "eV").unwrap(), 1.0);
+    }
+
+    #[test]
+    fn hartree_round_trips() {
+        for x in [1e-3_f64, 1.0, 42.0] {
+            let ev = convert_2_ev_from()["hartree"](x, "").unwrap();
+            let via_ev = convert_2_hartree_from()["eV"](ev, "").unwrap();
+            assert_relative_eq!(x, via_ev, max_relative = 1e-10);
+            let rcm = convert_2_rcm_from()["hartree"](x, "").unwrap();
+            let via_rcm = convert_2_hartree_from()["rcm"](rcm, "").unwrap();
+            assert_relative_eq!(x, via_rcm, max_relative = 1e-10);
+        }
+    }
+
+    #[test]
+    fn joule_conversions() {
+        let joule = ev_2_j(1.0, "J").unwrap();
+        assert_relative_eq!(1.0, convert_2_ev_from()["J"](joule, "J").unwrap());
+        assert_relative_eq!(
+            convert_2_ev_from()["J"](1e3, "J").unwrap(),
+            convert_2_ev_from()["kJ"](1.0, "kJ").unwrap()
+        );
+        assert_relative_eq!(
+            convert_2_ev_from()["J"](1e-6, "J").unwrap(),
+            convert_2_ev_from()["µJ"](1.0, "µJ").unwrap()
+        );
+        assert_relative_eq!(
+            ev_2_rcm(convert_2_ev_from()["mJ"](2.0, "mJ").unwrap(), "").unwrap(),
+            convert_2_rcm_from()["mJ"](2.0, "mJ").unwrap()
+        );
+        assert_relative_eq!(2.0, try_convert(2.0, "kJ", "kJ").unwrap());
+    }
+
+    #[test]
+    fn molar_energy_conversions() {
+        let hartree_in_ev = convert_2_ev_from()["hartree"](1.0, "").unwrap();
+        let kjmol = ev_2_kjmol(hartree_in_ev, "").unwrap();
+        assert_relative_eq!(2625.5, kjmol, max_relative = 1e-4);
+        let kcalmol = ev_2_kcalmol(hartree_in_ev, "").unwrap();
+        assert_relative_eq!(627.5, kcalmol, max_relative = 1e-4);
+        assert_relative_eq!(
+            hartree_in_ev,
+            convert_2_ev_from()["kJ/mol"](2_625.499_639_48, "").unwrap(),
+            max_relative = 1e-9
+        );
+        // kcal is a plain energy, kcal/mol is per particle; they differ by Avogadro's number.
+        assert_relative_eq!(
+            convert_2_ev_from()["kcal"](1.0, "").unwrap() / AVOGADRO_CONSTANT,
+            convert_2_ev_from()["kcal/mol"](1.0, "").unwrap()
+        );
+    }
+
+    #[test]
+    fn thermal_energy_conversions() {
+        assert_relative_eq!(
+            0.025_852,
+            kelvin_2_ev(300.0, "").unwrap(),
+            max_relative = 1e-4
+        );
+        assert_relative_eq!(208.5, kelvin_2_rcm(300.0, "").unwrap(), max_relative = 1e-3);
+        assert_relative_eq!(
+            300.0,
+            ev_2_kelvin(kelvin_2_ev(300.0, "").unwrap(), "").unwrap()
+        );
+        // The equivalence is opt-in, kelvin is not an energy unit of the maps.
+        assert!(!convert_2_ev_from().contains_key("K"));
+    }
+
+    #[test]
+    fn photon_frequency_conversions() {
+        let hz = rcm_2_hz(1000.0, "").unwrap();
+        assert_relative_eq!(29.979, hz / 1e12, max_relative = 1e-4);
+        assert_relative_eq!(
+            1000.0,
+            convert_2_rcm_from()["THz"](29.979_245_8, "THz").unwrap()
+        );
+        assert_relative_eq!(
+            convert_2_ev_from()["rcm"](1000.0, "").unwrap(),
+            convert_2_ev_from()["THz"](29.979_245_8, "THz").unwrap(),
+            max_relative = 1e-8
+        );
+        assert_relative_eq!(1.0, ev_2_thz(thz_2_ev(1.0, "").unwrap(), "").unwrap());
+        assert_relative_eq!(
+            convert_2_ev_from()["GHz"](1e3, "GHz").unwrap(),
+            convert_2_ev_from()["THz"](1.0, "THz").unwrap()
+        );
+    }
+
+    #[test]
+    fn wavelength_conversions() {
+        assert_relative_eq!(
+            1.0,
+            convert_2_ev_from()["nm"](1240.0, "").unwrap(),
+            max_relative = 1e-3
+        );
+        assert_relative_eq!(10_000.0, convert_2_rcm_from()["nm"](1000.0, "").unwrap());
+        assert_relative_eq!(500.0, try_convert(500.0, "nm", "nm").unwrap());
+        assert_relative_eq!(500.0, rcm_2_nm(nm_2_rcm(500.0, "").unwrap(), "").unwrap());
+        assert_eq!(Ok(f64::INFINITY), nm_2_ev(0.0, ""));
+        assert_eq!(Ok(f64::INFINITY), rcm_2_nm(-0.0, ""));
+    }
+
+    #[test]
+    fn angstrom_wavelengths() {
+        assert_relative_eq!(
+            ang_2_ev(1.0, "").unwrap() / 1e3,
+            12.398,
+            max_relative = 1e-4
+        );
+        assert_relative_eq!(
+            try_convert(1.0, "ang", "eV").unwrap(),
+            try_convert(0.1, "nm", "eV").unwrap(),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(1.5, ev_2_ang(ang_2_ev(1.5, "").unwrap(), "").unwrap());
+        assert_relative_eq!(
+            10.0,
+            try_convert(1.0, "nm", "ang").unwrap(),
+            max_relative = 1e-12
+        );
+        assert_eq!(
+            crate::convert(1.0, "ang", "nm"),
+            crate::length::try_convert(1.0, "ang", "nm")
+        );
+        assert_eq!(Ok(f64::INFINITY), ev_2_ang(0.0, ""));
+    }
+
+    #[test]
+    fn rydberg_conversions() {
+        assert_relative_eq!(
+            13.605_693_122,
+            convert_2_ev_from()["Ry"](1.0, "").unwrap(),
+            max_relative = 1e-10
+        );
+        assert_eq!(Ok(0.5), convert_2_hartree_from()["Ry"](1.0, ""));
+        assert_relative_eq!(0.5, try_convert(1.0, "Ry", "hartree").unwrap());
+        assert_relative_eq!(2.0, try_convert(1.0, "hartree", "Ry").unwrap());
+    }
+
+    #[test]
+    fn unknown_units_and_prefixes_are_errors() {
+        assert_relative_eq!(
+            try_convert(2.0, "eV", "rcm").unwrap(),
+            2.0 * EV_REC_CENTIMETRES_CONVERSION_FACTOR
+        );
+        assert_eq!(
+            try_convert(1.0, "eV", "furlong"),
+            Err(ConversionError::UnknownUnit("furlong".to_string()))
+        );
+        assert_eq!(
+            j_2_ev(1.0, "MJ"),
+            Err(ConversionError::UnknownPrefix("MJ".to_string()))
+        );
+    }
+
+    #[test]
+    fn single_precision_conversions() {
+        let ev = hartree_2_ev(1.0_f32, "").unwrap();
+        assert_relative_eq!(27.211_386_f32, ev, max_relative = 1e-6);
+        let back = ev_2_hartree(ev, "").unwrap();
+        assert_relative_eq!(1.0_f32, back, max_relative = 1e-6);
+        let rcm = ev_2_rcm::<f32>(2.0, "").unwrap();
+        assert_relative_eq!(16_131.088_f32, rcm, max_relative = 1e-6);
+        let kj = ev_2_j::<f32>(j_2_ev(1.0, "kJ").unwrap(), "kJ").unwrap();
+        assert_relative_eq!(1.0_f32, kj, max_relative = 1e-6);
+        assert_eq!(Ok(f32::INFINITY), nm_2_ev::<f32>(0.0, ""));
+    }
+
+    #[test]
+    fn const_conversions() {
+        const HARTREE_IN_RCM: f64 = hartree_2_rcm_const(1.0);
+        const TWO_EV_IN_RCM: f64 = ev_2_rcm_const(2.0);
+        assert_eq!(HARTREE_IN_RCM, hartree_2_rcm(1.0, "").unwrap());
+        assert_eq!(TWO_EV_IN_RCM, ev_2_rcm(2.0, "").unwrap());
+        assert_eq!(rcm_2_ev_const(1.0), rcm_2_ev(1.0, "").unwrap());
+        assert_eq!(hartree_2_ev_const(1.0), HARTREE_EV_CONVERSION_FACTOR);
+        assert_relative_eq!(ev_2_hartree_const(hartree_2_ev_const(1.0)), 1.0);
+        assert_relative_eq!(rcm_2_hartree_const(HARTREE_IN_RCM), 1.0);
+    }
 }
@@ -0,0 +1,74 @@
+//! This module offers the error type returned by the conversion functions.
+
+use alloc::string::String;
+use core::fmt;
+
+use crate::{Category, Dimension};
+
+/// Error returned when a conversion can not be carried out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConversionError {
+    /// The unit is not registered in any conversion map.
+    UnknownUnit(String),
+    /// The prefix passed to a conversion function is not supported by it.
+    UnknownPrefix(String),
+    /// The units belong to categories that can not be converted into each other.
+    DimensionMismatch {
+        from_category: Category,
+        to_category: Category,
+    },
+    /// The string does not have the form "value unit", or the value is not physical, e.g. a
+    /// molar mass that is not positive.
+    InvalidQuantity(String),
+    /// The unit has an offset zero point, so there is no single conversion factor.
+    AffineUnit(String),
+    /// The unit is inversely proportional to the other one, so there is no single conversion
+    /// factor.
+    ReciprocalUnit(String),
+    /// Both units are known to the category, but no chain of conversions connects them.
+    NoConversionPath { from: String, to: String },
+    /// The compound units have different dimensions.
+    IncompatibleDimensions { from: Dimension, to: Dimension },
+    /// The value to convert, or the converted value, is NaN or infinite.
+    NonFinite,
+    /// Zero was given in the unit to a reciprocal conversion, whose result would be infinite.
+    ReciprocalOfZero(String),
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionError::UnknownUnit(unit) => write!(f, "Unknown unit {}", unit),
+            ConversionError::UnknownPrefix(prefix) => write!(f, "Unknown prefix {}", prefix),
+            ConversionError::DimensionMismatch {
+                from_category,
+                to_category,
+            } => write!(f, "Can not convert {} to {}", from_category, to_category),
+            ConversionError::InvalidQuantity(input) => write!(f, "Invalid quantity {}", input),
+            ConversionError::AffineUnit(unit) => {
+                write!(f, "Unit {} is affine and has no conversion factor", unit)
+            }
+            ConversionError::ReciprocalUnit(unit) => {
+                write!(
+                    f,
+                    "Unit {} is reciprocal and has no conversion factor",
+                    unit
+                )
+            }
+            ConversionError::NoConversionPath { from, to } => {
+                write!(f, "No conversion path from {} to {}", from, to)
+            }
+            ConversionError::IncompatibleDimensions { from, to } => {
+                write!(f, "Can not convert {} to {}", from, to)
+            }
+            ConversionError::NonFinite => write!(f, "Value is not finite"),
+            ConversionError::ReciprocalOfZero(unit) => {
+                write!(f, "Zero {} has no finite reciprocal", unit)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConversionError {}
@@ -0,0 +1,89 @@
+//! This module offers a dump of all registered units with their conversion factors, e.g. for
+//! documentation. The rows are read from the conversion maps, so they list exactly the units
+//! `convert` knows.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{supported_units, Category, Unit};
+
+/// A registered unit with its conversion to the base unit of its category.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConversionRow {
+    pub category: Category,
+    /// Key of the unit in the conversion maps, e.g. `rcm`.
+    pub unit: &'static str,
+    /// Canonical symbol of the unit, e.g. `cm⁻¹`, or the key if the unit has no [`Unit`].
+    pub symbol: &'static str,
+    /// Key of the base unit of the category.
+    pub base: &'static str,
+    /// Size of the unit in the base unit, `None` for affine and reciprocal units, which have no
+    /// such factor.
+    pub factor: Option<f64>,
+}
+
+/// Returns a row for every unit of every category, ordered by category and then by unit.
+pub fn conversion_rows() -> Vec<ConversionRow> {
+    let mut rows = Vec::new();
+    for category in Category::ALL {
+        let base = category.base_unit();
+        for unit in supported_units(category) {
+            let proportional = !category.is_affine(unit) && !category.is_reciprocal(unit);
+            let symbol = Unit::ALL
+                .iter()
+                .find(|u| u.key() == unit)
+                .map_or(unit, |u| u.symbol());
+            rows.push(ConversionRow {
+                category,
+                unit,
+                symbol,
+                base,
+                factor: proportional
+                    .then(|| category.try_convert(1.0, unit, base).ok())
+                    .flatten(),
+            });
+        }
+    }
+    rows
+}
+
+/// Returns [`conversion_rows`] as tab separated values with a header line, to be pasted into a
+/// spreadsheet. Missing factors are left empty.
+pub fn export_table() -> String {
+    let mut table = String::from("category\tunit\tsymbol\tbase\tfactor\n");
+    for row in conversion_rows() {
+        let factor = row.factor.map_or_else(String::new, |f| format!("{:e}", f));
+        table.push_str(&format!(
+            "{}\t{}\t{}\t{}\t{}\n",
+            row.category, row.unit, row.symbol, row.base, factor
+        ));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codata::expected;
+
+    #[test]
+    fn lists_bohr_and_electronvolt() {
+        let table = export_table();
+        assert!(table.starts_with("category\tunit\tsymbol\tbase\tfactor\n"));
+        let bohr = format!(
+            "length\tbohr\ta₀\tm\t{}\n",
+            expected::BOHR_RADIUS_IN_METRES_DECIMAL
+        );
+        assert!(table.contains(&bohr));
+        assert!(table.contains("energy\teV\teV\teV\t1e0\n"));
+        assert!(table.contains("temperature\tdegC\t°C\tK\t\n"));
+        assert_eq!(
+            table.lines().count(),
+            1 + Category::ALL
+                .iter()
+                .map(|&c| supported_units(c).len())
+                .sum::<usize>()
+        );
+    }
+}
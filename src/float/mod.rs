@@ -0,0 +1,9 @@
+//! This module offers helpers for writing conversion functions generic over the float type.
+//! Conversion constants are stored as f64 and cast to the requested float type on use.
+
+use num_traits::Float;
+
+/// Casts an f64 conversion constant to the float type `T` of the conversion.
+pub(crate) fn cast<T: Float>(v: f64) -> T {
+    T::from(v).expect("conversion constants are representable in every float type")
+}
@@ -0,0 +1,39 @@
+//! This module offers force unit conversion functions in double precision, i.e. f64.
+
+use crate::table::conversion_table;
+
+const DYNE_TO_NEWTON: f64 = 1e-5;
+const POUND_FORCE_TO_NEWTON: f64 = 4.448_221_615_26;
+
+conversion_table!(
+    kind = "force",
+    category = Force,
+    a_kind = "a force",
+    base = "newton",
+    /// Returns the size of the force unit in newton.
+    factor = newtons_per,
+    to_base = to_newton,
+    from_base = from_newton,
+    to_map = convert_2_newton_from,
+    from_map = convert_from_newton_2,
+    units = {
+        "N" => 1.0,
+        "dyn" => DYNE_TO_NEWTON,
+        "lbf" => POUND_FORCE_TO_NEWTON,
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn dyne_and_pound_force() {
+        assert_relative_eq!(try_convert(1.0, "N", "dyn").unwrap(), 1e5);
+        assert_relative_eq!(try_convert(1.0, "lbf", "N").unwrap(), 4.448_221_615_26);
+        let lbf = try_convert(3.7, "N", "lbf").unwrap();
+        assert_relative_eq!(try_convert(lbf, "lbf", "N").unwrap(), 3.7);
+    }
+}
@@ -0,0 +1,148 @@
+//! This module offers formatting of converted values rounded to a number of significant figures
+//! and scaled to a fitting SI prefix.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+
+use crate::prefix::si_prefix_factor;
+
+/// Decimal exponents below this are formatted in scientific notation.
+const SMALLEST_FIXED_EXPONENT: i32 = -4;
+
+/// SI prefixes of powers of a thousand from the largest to the smallest, used by [`auto_scale`].
+const ENGINEERING_PREFIXES: [&str; 17] = [
+    "Y", "Z", "E", "P", "T", "G", "M", "k", "", "m", "µ", "n", "p", "f", "a", "z", "y",
+];
+
+/// Function to format a value rounded to significant figures followed by its unit, e.g.
+/// `format_quantity(52.917_72, "nm", 4)` gives `"52.92 nm"`.
+/// # Arguments
+///  * `value` - value to format
+///  * `unit` - unit symbol appended after a space
+///  * `significant_figures` - number of significant figures, at least one is always shown
+///
+pub fn format_quantity(value: f64, unit: &str, significant_figures: usize) -> String {
+    format!(
+        "{} {}",
+        format_significant(value, significant_figures),
+        unit
+    )
+}
+
+/// Function to format a value rounded to significant figures.
+///
+/// Like printf's `%g`, values with a decimal exponent below -4 or of at least the number of
+/// significant figures are written in scientific notation, e.g. `1.23e-7` or `6.02e23`, all
+/// others in fixed notation keeping trailing zeros, e.g. `0.0529` or `1.000`. Zero is formatted
+/// in fixed notation and non-finite values as by `Display`.
+/// # Arguments
+///  * `value` - value to format
+///  * `significant_figures` - number of significant figures, at least one is always shown
+///
+pub fn format_significant(value: f64, significant_figures: usize) -> String {
+    let significant_figures = significant_figures.max(1);
+    if !value.is_finite() {
+        return value.to_string();
+    }
+    let decimals = significant_figures - 1;
+    if value == 0.0 {
+        return format!("{:.*}", decimals, 0.0);
+    }
+    // The exponent is taken after rounding, so 9.996 to three figures counts as 1.00e1.
+    let scientific = format!("{:.*e}", decimals, value);
+    let exponent: i32 = scientific
+        .rsplit('e')
+        .next()
+        .and_then(|e| e.parse().ok())
+        .expect("scientific formatting of a finite value has an exponent");
+    if exponent < SMALLEST_FIXED_EXPONENT || exponent >= significant_figures as i32 {
+        scientific
+    } else {
+        let fixed_decimals = (decimals as i32 - exponent) as usize;
+        format!("{:.*}", fixed_decimals, value)
+    }
+}
+
+/// Function to scale a value given in an unprefixed unit to the SI prefix that puts it in
+/// [1, 1000), e.g. `auto_scale(5.29e-11, "m")` gives `(52.9, "pm")` and `auto_scale(1500.0, "m")`
+/// gives `(1.5, "km")`.
+///
+/// The prefixed units are understood by [`crate::convert`], so the scaled value converts back to
+/// `value` in `base_unit`. Zero and non-finite values keep the base unit. So do values too large
+/// or too small for the prefixes from yotta to yocto, which [`format_significant`] then writes in
+/// scientific notation.
+/// # Arguments
+///  * `value` - value in `base_unit`
+///  * `base_unit` - unprefixed unit symbol the prefix is put in front of, e.g. `m`, `s` or `g`
+///
+pub fn auto_scale(value: f64, base_unit: &str) -> (f64, String) {
+    let magnitude = value.abs();
+    if value == 0.0 || !value.is_finite() || magnitude >= 1e3 * si_prefix_factor("Y").unwrap() {
+        return (value, base_unit.to_string());
+    }
+    ENGINEERING_PREFIXES
+        .into_iter()
+        .map(|prefix| (prefix, si_prefix_factor(prefix).expect("SI prefix")))
+        .find(|&(_, factor)| magnitude >= factor)
+        .map_or_else(
+            || (value, base_unit.to_string()),
+            |(prefix, factor)| (value / factor, format!("{}{}", prefix, base_unit)),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn rounds_to_significant_figures() {
+        assert_eq!(format_quantity(5.291_772e-2, "nm", 3), "0.0529 nm");
+        assert_eq!(format_quantity(52.917_721, "nm", 4), "52.92 nm");
+        assert_eq!(format_significant(-5.291_772e-2, 3), "-0.0529");
+        assert_eq!(format_significant(9.996, 3), "10.0");
+        assert_eq!(format_significant(0.0, 3), "0.00");
+        assert_eq!(format_significant(1.0, 0), "1");
+        assert_eq!(format_significant(1.234_5e-7, 3), "1.23e-7");
+        assert_eq!(format_significant(6.022_140_76e23, 3), "6.02e23");
+        assert_eq!(format_significant(f64::INFINITY, 3), "inf");
+    }
+
+    #[test]
+    fn scales_to_si_prefixes() {
+        let (value, unit) = auto_scale(5.29e-11, "m");
+        assert_relative_eq!(value, 52.9, max_relative = 1e-12);
+        assert_eq!(unit, "pm");
+        assert_eq!(auto_scale(1500.0, "m"), (1.5, "km".to_string()));
+        let (value, unit) = auto_scale(-2.5e-6, "s");
+        assert_relative_eq!(value, -2.5, max_relative = 1e-12);
+        assert_eq!(unit, "µs");
+        assert_eq!(auto_scale(1e-9, "s"), (1.0, "ns".to_string()));
+        assert_eq!(auto_scale(42.0, "g"), (42.0, "g".to_string()));
+        assert_eq!(auto_scale(0.0, "m"), (0.0, "m".to_string()));
+        assert_eq!(auto_scale(2e-30, "m"), (2e-30, "m".to_string()));
+        assert_eq!(auto_scale(2e30, "m"), (2e30, "m".to_string()));
+        assert_eq!(format_significant(auto_scale(2e30, "m").0, 3), "2.00e30");
+    }
+
+    #[test]
+    fn scaled_values_convert_back() {
+        for (value, base_unit) in [
+            (5.29e-11, "m"),
+            (1500.0, "m"),
+            (3.2e10, "m"),
+            (2.5e-6, "s"),
+            (4.2e4, "Pa"),
+            (7.5e3, "eV"),
+            (0.042, "g"),
+        ] {
+            let (scaled, unit) = auto_scale(value, base_unit);
+            assert_relative_eq!(
+                crate::convert(scaled, &unit, base_unit).unwrap(),
+                value,
+                max_relative = 1e-12
+            );
+        }
+    }
+}
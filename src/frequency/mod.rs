@@ -0,0 +1,47 @@
+//! This module offers frequency unit conversion functions in double precision, i.e. f64.
+//! Angular frequencies in rad/s are related to hertz by a factor of 2π.
+
+use crate::table::conversion_table;
+
+const KILO: f64 = 1e3;
+const MEGA: f64 = 1e6;
+const GIGA: f64 = 1e9;
+const TERA: f64 = 1e12;
+const RADIANS_PER_SECOND_TO_HERTZ: f64 = 1.0 / core::f64::consts::TAU;
+
+conversion_table!(
+    kind = "frequency",
+    category = Frequency,
+    a_kind = "a frequency",
+    base = "hertz",
+    /// Returns the size of the (prefixed) frequency unit in hertz. One rad/s corresponds to 1/(2π) Hz.
+    factor = hertz_per,
+    to_base = to_hertz,
+    from_base = from_hertz,
+    to_map = convert_2_hz_from,
+    from_map = convert_from_hz_2,
+    units = {
+        "Hz" => 1.0,
+        "kHz" => KILO,
+        "MHz" => MEGA,
+        "GHz" => GIGA,
+        "THz" => TERA,
+        "rad/s" => RADIANS_PER_SECOND_TO_HERTZ,
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn angular_frequency() {
+        let omega = try_convert(1.0, "Hz", "rad/s").unwrap();
+        assert_relative_eq!(omega, core::f64::consts::TAU);
+        assert_relative_eq!(try_convert(omega, "rad/s", "Hz").unwrap(), 1.0);
+        assert_relative_eq!(try_convert(2.5, "GHz", "MHz").unwrap(), 2_500.0);
+        assert_relative_eq!(try_convert(1.0, "THz", "kHz").unwrap(), 1e9);
+    }
+}
@@ -0,0 +1,288 @@
+//! This module offers a conversion graph composing registered conversions into multi-hop
+//! conversions, so not every pair of units needs a direct entry.
+
+use alloc::collections::VecDeque;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::collections::Map;
+use crate::{Category, ConversionError};
+
+/// A conversion between two units, i.e. an edge of the [`ConversionGraph`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Edge {
+    /// The target value is the source value times the factor, e.g. bohr to m.
+    Factor(f64),
+    /// The target value is the constant divided by the source value, e.g. nm to eV.
+    Reciprocal(f64),
+}
+
+impl Edge {
+    /// Returns the edge of the opposite direction.
+    fn inverse(self) -> Edge {
+        match self {
+            Edge::Factor(factor) => Edge::Factor(1.0 / factor),
+            Edge::Reciprocal(constant) => Edge::Reciprocal(constant),
+        }
+    }
+
+    fn apply(self, value: f64) -> f64 {
+        match self {
+            Edge::Factor(factor) => value * factor,
+            Edge::Reciprocal(constant) => constant / value,
+        }
+    }
+}
+
+/// Units as nodes and known conversions as edges, one graph per category.
+///
+/// A conversion is found by a breadth-first search from the source to the target unit, so it
+/// takes the fewest hops, and the edges along the path are applied one after another. Applying
+/// them to the value rather than multiplying factors keeps reciprocal edges correct when they
+/// are mixed with proportional ones.
+#[derive(Debug, Clone, Default)]
+pub struct ConversionGraph {
+    edges: Map<Category, Map<String, Vec<(String, Edge)>>>,
+    /// Registered units without edges as their conversions have an offset, e.g. `degC`.
+    affine: Vec<String>,
+}
+
+impl ConversionGraph {
+    /// Creates a graph without any units.
+    pub fn new() -> Self {
+        ConversionGraph::default()
+    }
+
+    /// Creates a graph of the units registered in the categories, with an edge from every unit
+    /// to the base unit of its category and back, e.g. from `bohr` to `m` and from `m` to `bohr`.
+    ///
+    /// Both directions take their factor from the conversion maps, and reciprocal units like
+    /// `nm` as a photon energy become reciprocal edges. Affine units like `degC` have neither
+    /// kind of edge, converting them gives `AffineUnit`.
+    pub fn from_registry() -> Self {
+        let mut graph = ConversionGraph::new();
+        for category in Category::ALL {
+            let base = category.base_unit();
+            for (&unit, to_base) in category.to_base_map() {
+                if category.is_affine(unit) {
+                    graph.affine.push(unit.to_string());
+                    continue;
+                }
+                if unit == base {
+                    continue;
+                }
+                let from_base = category.to_unit_map()[unit];
+                let edge: fn(f64) -> Edge = if category.is_reciprocal(unit) {
+                    Edge::Reciprocal
+                } else {
+                    Edge::Factor
+                };
+                let (to_size, from_size) = (to_base(1.0, unit), from_base(1.0, unit));
+                graph.insert(
+                    category,
+                    unit,
+                    base,
+                    edge(to_size.expect("registered unit")),
+                );
+                graph.insert(
+                    category,
+                    base,
+                    unit,
+                    edge(from_size.expect("registered unit")),
+                );
+            }
+        }
+        graph
+    }
+
+    /// Registers the conversion `edge` from `from` to `to` in `category`. The opposite direction
+    /// is registered as well.
+    pub fn add_edge(&mut self, category: Category, from: &str, to: &str, edge: Edge) {
+        self.insert(category, from, to, edge);
+        self.insert(category, to, from, edge.inverse());
+    }
+
+    /// Registers the conversion `edge` from `from` to `to` in `category` in this direction only.
+    fn insert(&mut self, category: Category, from: &str, to: &str, edge: Edge) {
+        self.edges
+            .entry(category)
+            .or_default()
+            .entry(from.to_string())
+            .or_default()
+            .push((to.to_string(), edge));
+    }
+
+    fn knows(&self, category: Category, unit: &str) -> bool {
+        self.edges
+            .get(&category)
+            .is_some_and(|nodes| nodes.contains_key(unit))
+    }
+
+    /// Returns the edges of the shortest path from `from` to `to` in `category`, if any.
+    fn path(&self, category: Category, from: &str, to: &str) -> Option<Vec<Edge>> {
+        let nodes = self.edges.get(&category)?;
+        let mut reached_by: Map<&str, (&str, Edge)> = Map::new();
+        let mut queue = VecDeque::from([from]);
+        while let Some(unit) = queue.pop_front() {
+            if unit == to {
+                let mut path = Vec::new();
+                let mut current = to;
+                while current != from {
+                    let (previous, edge) = reached_by[current];
+                    path.push(edge);
+                    current = previous;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for (next, edge) in nodes.get(unit).into_iter().flatten() {
+                if next != from && !reached_by.contains_key(next.as_str()) {
+                    reached_by.insert(next, (unit, *edge));
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// Converts `value` from `from` to `to` by composing the conversions along the shortest
+    /// path between the units.
+    ///
+    /// Units known to several categories are converted in the category [`crate::convert`] would
+    /// use. Returns `UnknownUnit` or `DimensionMismatch` like [`crate::convert`],
+    /// `NoConversionPath` if the units are in the same category but not connected, and
+    /// `AffineUnit` for affine units of a graph [`ConversionGraph::from_registry`].
+    pub fn convert(&self, value: f64, from: &str, to: &str) -> Result<f64, ConversionError> {
+        if let Some(unit) = [from, to]
+            .into_iter()
+            .find(|unit| self.affine.iter().any(|affine| affine == unit))
+        {
+            return Err(ConversionError::AffineUnit(unit.to_string()));
+        }
+        let mut categories = Category::ALL
+            .into_iter()
+            .filter(|&c| self.knows(c, from) && self.knows(c, to));
+        // As in `crate::convert`, photon units like `Hz` are converted as frequencies.
+        if let Some(category) = categories.next().map(|c| match c {
+            Category::Energy => categories.next().unwrap_or(c),
+            _ => c,
+        }) {
+            let path =
+                self.path(category, from, to)
+                    .ok_or_else(|| ConversionError::NoConversionPath {
+                        from: from.to_string(),
+                        to: to.to_string(),
+                    })?;
+            return Ok(path.into_iter().fold(value, |v, edge| edge.apply(v)));
+        }
+        let category_of = |unit: &str| {
+            Category::ALL
+                .into_iter()
+                .find(|&c| self.knows(c, unit))
+                .ok_or_else(|| ConversionError::UnknownUnit(unit.to_string()))
+        };
+        Err(ConversionError::DimensionMismatch {
+            from_category: category_of(from)?,
+            to_category: category_of(to)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn composes_multi_hop_conversions() {
+        let mut graph = ConversionGraph::new();
+        graph.add_edge(
+            Category::Length,
+            "bohr",
+            "m",
+            Edge::Factor(5.291_772_109_03e-11),
+        );
+        graph.add_edge(Category::Length, "m", "ang", Edge::Factor(1e10));
+        graph.add_edge(
+            Category::Energy,
+            "nm",
+            "eV",
+            Edge::Reciprocal(1_239.841_984),
+        );
+        graph.add_edge(Category::Energy, "eV", "meV", Edge::Factor(1e3));
+        graph.add_edge(Category::Energy, "cm", "rcm", Edge::Reciprocal(1.0));
+
+        assert_relative_eq!(
+            graph.convert(1.0, "bohr", "ang").unwrap(),
+            0.529_177_210_903
+        );
+        assert_relative_eq!(
+            graph.convert(1.0, "ang", "bohr").unwrap(),
+            1.889_726_124_6,
+            max_relative = 1e-10
+        );
+        assert_relative_eq!(
+            graph.convert(1_239.841_984, "nm", "meV").unwrap(),
+            1e3,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            graph.convert(2e3, "meV", "nm").unwrap(),
+            619.920_992,
+            max_relative = 1e-12
+        );
+        assert_eq!(
+            graph.convert(1.0, "nm", "rcm"),
+            Err(ConversionError::NoConversionPath {
+                from: "nm".to_string(),
+                to: "rcm".to_string(),
+            })
+        );
+        assert_eq!(
+            graph.convert(1.0, "bohr", "eV"),
+            Err(ConversionError::DimensionMismatch {
+                from_category: Category::Length,
+                to_category: Category::Energy,
+            })
+        );
+    }
+
+    #[test]
+    fn composes_the_registered_conversions() {
+        let graph = ConversionGraph::from_registry();
+        assert_eq!(
+            graph.path(Category::Length, "bohr", "ang").unwrap().len(),
+            2
+        );
+        assert_relative_eq!(
+            graph.convert(1.0, "bohr", "ang").unwrap(),
+            crate::convert(1.0, "bohr", "ang").unwrap(),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            graph.convert(500.0, "nm", "rcm").unwrap(),
+            crate::convert(500.0, "nm", "rcm").unwrap(),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            graph.convert(2.0, "hartree", "kcal/mol").unwrap(),
+            crate::convert(2.0, "hartree", "kcal/mol").unwrap(),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            graph.convert(1.0, "Hz", "rad/s").unwrap(),
+            core::f64::consts::TAU,
+            max_relative = 1e-15
+        );
+        assert_eq!(
+            graph.convert(1.0, "degC", "K"),
+            Err(ConversionError::AffineUnit("degC".to_string()))
+        );
+        assert_eq!(
+            graph.convert(1.0, "K", "degF"),
+            Err(ConversionError::AffineUnit("degF".to_string()))
+        );
+        assert_relative_eq!(graph.convert(1.0, "K", "degR").unwrap(), 1.8);
+    }
+}
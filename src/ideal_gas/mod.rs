@@ -0,0 +1,187 @@
+//! This module offers helpers for the ideal gas law PV = nRT in double precision, i.e. f64.
+//! Temperatures and pressures are given in any unit of the temperature and pressure modules,
+//! volumes in litres like the concentrations of the amount module.
+
+use alloc::format;
+
+use crate::{pressure, temperature, ConversionError};
+
+/// Molar gas constant R = N_A·k_B in J/(mol·K), exact since the 2019 SI redefinition and given
+/// here to ten significant digits.
+pub const GAS_CONSTANT: f64 = 8.314_462_618;
+
+const CUBIC_METRES_PER_LITRE: f64 = 1e-3;
+
+/// Converts the pressure to pascal. Returns `InvalidQuantity` if it is not positive, as the
+/// volume of a gas at zero pressure is infinite.
+fn pascals(pressure: f64, pressure_unit: &str) -> Result<f64, ConversionError> {
+    let pascals = pressure::try_convert(pressure, pressure_unit, "Pa")?;
+    if pascals > 0.0 {
+        Ok(pascals)
+    } else {
+        Err(ConversionError::InvalidQuantity(format!(
+            "{} {}",
+            pressure, pressure_unit
+        )))
+    }
+}
+
+/// Converts the temperature to kelvin. Returns `InvalidQuantity` if it is not above absolute
+/// zero.
+fn kelvins(temperature: f64, temperature_unit: &str) -> Result<f64, ConversionError> {
+    let kelvins = temperature::try_convert(temperature, temperature_unit, "K")?;
+    if kelvins > 0.0 {
+        Ok(kelvins)
+    } else {
+        Err(ConversionError::InvalidQuantity(format!(
+            "{} {}",
+            temperature, temperature_unit
+        )))
+    }
+}
+
+/// Function to compute the molar volume V_m = RT/P of an ideal gas in litres per mole.
+/// # Arguments
+///  * `temperature` - temperature of the gas in `temperature_unit`
+///  * `temperature_unit` - unit of the temperature module, e.g. `K` or `degC`
+///  * `pressure` - pressure of the gas in `pressure_unit`
+///  * `pressure_unit` - unit of the pressure module, e.g. `Pa` or `atm`
+///
+pub fn molar_volume(
+    temperature: f64,
+    temperature_unit: &str,
+    pressure: f64,
+    pressure_unit: &str,
+) -> Result<f64, ConversionError> {
+    let kelvins = kelvins(temperature, temperature_unit)?;
+    Ok(GAS_CONSTANT * kelvins / pascals(pressure, pressure_unit)? / CUBIC_METRES_PER_LITRE)
+}
+
+/// Function to compute the volume V = nRT/P of an amount of ideal gas in litres.
+/// # Arguments
+///  * `moles` - amount of gas in mol
+///  * `temperature` - temperature of the gas in `temperature_unit`
+///  * `temperature_unit` - unit of the temperature module, e.g. `K` or `degC`
+///  * `pressure` - pressure of the gas in `pressure_unit`
+///  * `pressure_unit` - unit of the pressure module, e.g. `Pa` or `atm`
+///
+pub fn volume(
+    moles: f64,
+    temperature: f64,
+    temperature_unit: &str,
+    pressure: f64,
+    pressure_unit: &str,
+) -> Result<f64, ConversionError> {
+    Ok(moles * molar_volume(temperature, temperature_unit, pressure, pressure_unit)?)
+}
+
+/// Function to compute the pressure P = nRT/V of an amount of ideal gas.
+/// # Arguments
+///  * `moles` - amount of gas in mol
+///  * `litres` - volume of the gas in litres
+///  * `temperature` - temperature of the gas in `temperature_unit`
+///  * `temperature_unit` - unit of the temperature module, e.g. `K` or `degC`
+///  * `pressure_unit` - pressure unit of the result, e.g. `Pa` or `atm`
+///
+pub fn pressure(
+    moles: f64,
+    litres: f64,
+    temperature: f64,
+    temperature_unit: &str,
+    pressure_unit: &str,
+) -> Result<f64, ConversionError> {
+    let kelvins = kelvins(temperature, temperature_unit)?;
+    let pascals = moles * GAS_CONSTANT * kelvins / (litres * CUBIC_METRES_PER_LITRE);
+    pressure::try_convert(pascals, "Pa", pressure_unit)
+}
+
+/// Function to compute the amount n = PV/(RT) of an ideal gas in mol.
+/// # Arguments
+///  * `pressure` - pressure of the gas in `pressure_unit`
+///  * `pressure_unit` - unit of the pressure module, e.g. `Pa` or `atm`
+///  * `litres` - volume of the gas in litres
+///  * `temperature` - temperature of the gas in `temperature_unit`
+///  * `temperature_unit` - unit of the temperature module, e.g. `K` or `degC`
+///
+pub fn moles(
+    pressure: f64,
+    pressure_unit: &str,
+    litres: f64,
+    temperature: f64,
+    temperature_unit: &str,
+) -> Result<f64, ConversionError> {
+    let kelvins = kelvins(temperature, temperature_unit)?;
+    Ok(
+        pascals(pressure, pressure_unit)? * litres * CUBIC_METRES_PER_LITRE
+            / (GAS_CONSTANT * kelvins),
+    )
+}
+
+/// Function to compute the temperature T = PV/(nR) of an amount of ideal gas.
+/// # Arguments
+///  * `pressure` - pressure of the gas in `pressure_unit`
+///  * `pressure_unit` - unit of the pressure module, e.g. `Pa` or `atm`
+///  * `litres` - volume of the gas in litres
+///  * `moles` - amount of gas in mol
+///  * `temperature_unit` - temperature unit of the result, e.g. `K` or `degC`
+///
+pub fn temperature(
+    pressure: f64,
+    pressure_unit: &str,
+    litres: f64,
+    moles: f64,
+    temperature_unit: &str,
+) -> Result<f64, ConversionError> {
+    let kelvins = pascals(pressure, pressure_unit)? * litres * CUBIC_METRES_PER_LITRE
+        / (moles * GAS_CONSTANT);
+    temperature::try_convert(kelvins, "K", temperature_unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn molar_volume_at_standard_conditions() {
+        assert_relative_eq!(
+            molar_volume(273.15, "K", 101_325.0, "Pa").unwrap(),
+            22.413_969,
+            max_relative = 1e-7
+        );
+        assert_eq!(
+            molar_volume(0.0, "degC", 1.0, "atm"),
+            molar_volume(273.15, "K", 101_325.0, "Pa")
+        );
+        assert_eq!(
+            molar_volume(273.15, "K", 0.0, "Pa"),
+            Err(ConversionError::InvalidQuantity("0 Pa".to_string()))
+        );
+        assert_eq!(
+            molar_volume(273.15, "K", 1.0, "parsnip"),
+            Err(ConversionError::UnknownUnit("parsnip".to_string()))
+        );
+    }
+
+    #[test]
+    fn gas_law_round_trips() {
+        let litres = volume(2.0, 25.0, "degC", 1.0, "bar").unwrap();
+        assert_relative_eq!(
+            pressure(2.0, litres, 25.0, "degC", "bar").unwrap(),
+            1.0,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            moles(1.0, "bar", litres, 298.15, "K").unwrap(),
+            2.0,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            temperature(1.0, "bar", litres, 2.0, "degC").unwrap(),
+            25.0,
+            max_relative = 1e-12
+        );
+    }
+}
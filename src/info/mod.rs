@@ -0,0 +1,312 @@
+//! This module offers metadata of the registered units, i.e. their full name, symbol and
+//! dimension, e.g. to show "Bohr radius (a₀)" for the `bohr` key in a user interface.
+
+use crate::{Category, Dimension};
+
+/// Metadata of a unit registered in a category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnitInfo {
+    /// Key of the unit in the conversion maps, e.g. `bohr`.
+    pub key: &'static str,
+    /// Full name of the unit, e.g. `Bohr radius`.
+    pub full_name: &'static str,
+    /// Canonical symbol of the unit, e.g. `a₀`.
+    pub symbol: &'static str,
+    /// Dimension of the category of the unit. The photon units of the energy category, e.g.
+    /// `rcm` or `Hz` as hν, are energy equivalents and have the dimension of an energy.
+    pub dimension: Dimension,
+}
+
+/// Defines the metadata of the units of a category as constant slice.
+macro_rules! unit_infos {
+    ($name:ident, $category:ident; $($key:literal => $full_name:literal, $symbol:literal;)+) => {
+        const $name: &[UnitInfo] = &[$(UnitInfo {
+            key: $key,
+            full_name: $full_name,
+            symbol: $symbol,
+            dimension: Category::$category.dimension(),
+        }),+];
+    };
+}
+
+unit_infos!(LENGTH, Length;
+    "AU" => "astronomical unit", "AU";
+    "ang" => "angstrom", "Å";
+    "bohr" => "Bohr radius", "a₀";
+    "cm" => "centimetre", "cm";
+    "fm" => "femtometre", "fm";
+    "ft" => "foot", "ft";
+    "in" => "inch", "in";
+    "ly" => "light year", "ly";
+    "m" => "metre", "m";
+    "mi" => "mile", "mi";
+    "micron" => "micrometre", "µm";
+    "mm" => "millimetre", "mm";
+    "mu" => "micrometre", "µm";
+    "nm" => "nanometre", "nm";
+    "pc" => "parsec", "pc";
+    "pm" => "picometre", "pm";
+    "um" => "micrometre", "µm";
+    "yd" => "yard", "yd";
+    "µm" => "micrometre", "µm";
+    "μm" => "micrometre", "µm";
+);
+
+unit_infos!(ENERGY, Energy;
+    "GHz" => "gigahertz photon energy", "GHz";
+    "Hz" => "hertz photon energy", "Hz";
+    "J" => "joule", "J";
+    "Ry" => "Rydberg", "Ry";
+    "THz" => "terahertz photon energy", "THz";
+    "ang" => "angstrom photon wavelength", "Å";
+    "eV" => "electronvolt", "eV";
+    "hartree" => "Hartree", "Eₕ";
+    "joule" => "joule", "J";
+    "kJ" => "kilojoule", "kJ";
+    "kJ/mol" => "kilojoule per mole", "kJ/mol";
+    "kK" => "kilokayser", "kK";
+    "kcal" => "kilocalorie", "kcal";
+    "kcal/mol" => "kilocalorie per mole", "kcal/mol";
+    "mJ" => "millijoule", "mJ";
+    "nm" => "nanometre photon wavelength", "nm";
+    "rad/s" => "radian per second photon energy", "rad/s";
+    "rcm" => "reciprocal centimetre", "cm⁻¹";
+    "rm" => "reciprocal metre", "m⁻¹";
+    "uJ" => "microjoule", "µJ";
+    "µJ" => "microjoule", "µJ";
+    "μJ" => "microjoule", "µJ";
+);
+
+unit_infos!(TEMPERATURE, Temperature;
+    "K" => "kelvin", "K";
+    "degC" => "degree Celsius", "°C";
+    "degF" => "degree Fahrenheit", "°F";
+    "degR" => "degree Rankine", "°R";
+);
+
+unit_infos!(MASS, Mass;
+    "Da" => "dalton", "Da";
+    "amu" => "atomic mass unit", "u";
+    "g" => "gram", "g";
+    "kg" => "kilogram", "kg";
+    "lb" => "pound", "lb";
+    "me" => "electron mass", "mₑ";
+);
+
+unit_infos!(TIME, Time;
+    "a" => "Julian year", "a";
+    "as" => "attosecond", "as";
+    "au" => "atomic unit of time", "ħ/Eₕ";
+    "d" => "day", "d";
+    "fs" => "femtosecond", "fs";
+    "h" => "hour", "h";
+    "min" => "minute", "min";
+    "ms" => "millisecond", "ms";
+    "ns" => "nanosecond", "ns";
+    "ps" => "picosecond", "ps";
+    "s" => "second", "s";
+    "us" => "microsecond", "µs";
+    "µs" => "microsecond", "µs";
+    "μs" => "microsecond", "µs";
+);
+
+unit_infos!(PRESSURE, Pressure;
+    "GPa" => "gigapascal", "GPa";
+    "MPa" => "megapascal", "MPa";
+    "Pa" => "pascal", "Pa";
+    "atm" => "standard atmosphere", "atm";
+    "bar" => "bar", "bar";
+    "barg" => "bar gauge", "barg";
+    "kPa" => "kilopascal", "kPa";
+    "mmHg" => "millimetre of mercury", "mmHg";
+    "psi" => "pound per square inch", "psi";
+    "psig" => "pound per square inch gauge", "psig";
+    "torr" => "torr", "Torr";
+);
+
+unit_infos!(FREQUENCY, Frequency;
+    "GHz" => "gigahertz", "GHz";
+    "Hz" => "hertz", "Hz";
+    "MHz" => "megahertz", "MHz";
+    "THz" => "terahertz", "THz";
+    "kHz" => "kilohertz", "kHz";
+    "rad/s" => "radian per second", "rad/s";
+);
+
+unit_infos!(FORCE, Force;
+    "N" => "newton", "N";
+    "dyn" => "dyne", "dyn";
+    "lbf" => "pound-force", "lbf";
+);
+
+unit_infos!(POWER, Power;
+    "MW" => "megawatt", "MW";
+    "PS" => "metric horsepower", "PS";
+    "W" => "watt", "W";
+    "cal/s" => "calorie per second", "cal/s";
+    "hp" => "horsepower", "hp";
+    "kW" => "kilowatt", "kW";
+);
+
+unit_infos!(CHARGE, Charge;
+    "C" => "coulomb", "C";
+    "e" => "elementary charge", "e";
+    "esu" => "statcoulomb", "esu";
+);
+
+unit_infos!(ANGLE, Angle;
+    "arcmin" => "minute of arc", "′";
+    "arcsec" => "second of arc", "″";
+    "deg" => "degree", "°";
+    "grad" => "gradian", "gon";
+    "rad" => "radian", "rad";
+);
+
+unit_infos!(AREA, Area;
+    "ang²" => "square angstrom", "Å²";
+    "bohr²" => "square Bohr radius", "a₀²";
+    "cm²" => "square centimetre", "cm²";
+    "m²" => "square metre", "m²";
+);
+
+unit_infos!(SPEED, Speed;
+    "c" => "speed of light", "c";
+    "km/h" => "kilometre per hour", "km/h";
+    "kn" => "knot", "kn";
+    "m/s" => "metre per second", "m/s";
+    "mph" => "mile per hour", "mph";
+);
+
+unit_infos!(DIPOLE, Dipole;
+    "Cm" => "coulomb metre", "C·m";
+    "D" => "debye", "D";
+    "au" => "atomic unit of electric dipole moment", "e·a₀";
+);
+
+unit_infos!(MAGNETIC_FIELD, MagneticField;
+    "G" => "gauss", "G";
+    "T" => "tesla", "T";
+    "au" => "atomic unit of magnetic flux density", "ħ/(e·a₀²)";
+);
+
+unit_infos!(MAGNETIC_MOMENT, MagneticMoment;
+    "J/T" => "joule per tesla", "J/T";
+    "muB" => "Bohr magneton", "μB";
+    "muN" => "nuclear magneton", "μN";
+);
+
+unit_infos!(DATA, Data;
+    "B" => "byte", "B";
+    "GB" => "gigabyte", "GB";
+    "Gbit" => "gigabit", "Gbit";
+    "GiB" => "gibibyte", "GiB";
+    "KB" => "kilobyte", "KB";
+    "KiB" => "kibibyte", "KiB";
+    "MB" => "megabyte", "MB";
+    "Mbit" => "megabit", "Mbit";
+    "MiB" => "mebibyte", "MiB";
+    "TB" => "terabyte", "TB";
+    "TiB" => "tebibyte", "TiB";
+    "bit" => "bit", "bit";
+    "kB" => "kilobyte", "kB";
+    "kbit" => "kilobit", "kbit";
+);
+
+unit_infos!(ACCELERATION, Acceleration;
+    "Gal" => "gal", "Gal";
+    "g" => "standard gravity", "g₀";
+    "m/s2" => "metre per second squared", "m/s²";
+);
+
+unit_infos!(SOLID_ANGLE, SolidAngle;
+    "deg2" => "square degree", "deg²";
+    "sr" => "steradian", "sr";
+);
+
+unit_infos!(MOMENTUM, Momentum;
+    "au" => "atomic unit of momentum", "ħ/a₀";
+    "kg*m/s" => "kilogram metre per second", "kg·m/s";
+    "kg·m/s" => "kilogram metre per second", "kg·m/s";
+);
+
+unit_infos!(VISCOSITY, Viscosity;
+    "P" => "poise", "P";
+    "Pa.s" => "pascal second", "Pa·s";
+    "cP" => "centipoise", "cP";
+);
+
+unit_infos!(ELECTRIC_FIELD, ElectricField;
+    "V/cm" => "volt per centimetre", "V/cm";
+    "V/m" => "volt per metre", "V/m";
+    "au" => "atomic unit of electric field", "Eₕ/(e·a₀)";
+);
+
+/// Returns the metadata of the units of `category`.
+fn unit_infos(category: Category) -> &'static [UnitInfo] {
+    match category {
+        Category::Length => LENGTH,
+        Category::Energy => ENERGY,
+        Category::Temperature => TEMPERATURE,
+        Category::Mass => MASS,
+        Category::Time => TIME,
+        Category::Pressure => PRESSURE,
+        Category::Frequency => FREQUENCY,
+        Category::Force => FORCE,
+        Category::Power => POWER,
+        Category::Charge => CHARGE,
+        Category::Angle => ANGLE,
+        Category::Area => AREA,
+        Category::Speed => SPEED,
+        Category::Dipole => DIPOLE,
+        Category::MagneticField => MAGNETIC_FIELD,
+        Category::MagneticMoment => MAGNETIC_MOMENT,
+        Category::Data => DATA,
+        Category::Acceleration => ACCELERATION,
+        Category::SolidAngle => SOLID_ANGLE,
+        Category::Momentum => MOMENTUM,
+        Category::Viscosity => VISCOSITY,
+        Category::ElectricField => ELECTRIC_FIELD,
+    }
+}
+
+/// Returns the metadata of the unit `key` in `category`, if it is registered there.
+pub fn unit_info_in(category: Category, key: &str) -> Option<&'static UnitInfo> {
+    unit_infos(category).iter().find(|info| info.key == key)
+}
+
+/// Returns the metadata of the unit `key` in the first category knowing it, see
+/// [`Category::of`]. Use [`unit_info_in`] for keys like `au` known to several categories.
+pub fn unit_info(key: &str) -> Option<&'static UnitInfo> {
+    unit_info_in(Category::of(key)?, key)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::supported_units;
+
+    #[test]
+    fn reciprocal_centimetre_is_an_energy() {
+        let rcm = unit_info("rcm").unwrap();
+        assert_eq!(rcm.symbol, "cm⁻¹");
+        assert_eq!(rcm.full_name, "reciprocal centimetre");
+        assert_eq!(rcm.dimension, Category::Energy.dimension());
+        assert_eq!(unit_info("bohr").unwrap().full_name, "Bohr radius");
+        assert_eq!(
+            unit_info_in(Category::Frequency, "Hz").unwrap().dimension,
+            Category::Frequency.dimension()
+        );
+        assert_eq!(unit_info("parsnip"), None);
+    }
+
+    #[test]
+    fn every_unit_has_metadata() {
+        for category in Category::ALL {
+            let mut keys: Vec<&str> = unit_infos(category).iter().map(|info| info.key).collect();
+            keys.sort_unstable();
+            assert_eq!(keys, supported_units(category), "{}", category);
+        }
+    }
+}
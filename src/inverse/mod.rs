@@ -0,0 +1,62 @@
+//! This module offers the derivation of inverse conversion maps from forward ones.
+//!
+//! Most units are proportional to the base unit of their category, so the conversion from the
+//! base unit is the division by the factor the forward conversion multiplies with. Deriving it
+//! means only the forward direction has to be registered and the two can not drift apart.
+//! Reciprocal and affine units opt out by supplying their inverse explicitly.
+
+use crate::category::Callback;
+use crate::collections::Map;
+use crate::lookup::get_converter;
+use crate::ConversionError;
+
+/// Gives access to a forward map, converting the units of a category to its base unit.
+pub(crate) trait Forward {
+    fn forward() -> &'static Map<&'static str, Callback>;
+}
+
+/// Converts `v` from the base unit to `unit` by dividing by the factor of the forward
+/// conversion, i.e. the value of one `unit` in the base unit.
+fn derived_inverse<F: Forward>(v: f64, unit: &str) -> Result<f64, ConversionError> {
+    let to_base = get_converter(F::forward(), unit)?;
+    Ok(v / to_base(1.0, unit)?)
+}
+
+/// Builds the inverse of the forward map of `F`. Every unit gets the derived inverse unless it is
+/// listed in `explicit`, which is meant for reciprocal and affine units.
+pub(crate) fn invert<F: Forward>(
+    explicit: &[(&'static str, Callback)],
+) -> Map<&'static str, Callback> {
+    let mut t: Map<&'static str, Callback> = F::forward()
+        .keys()
+        .map(|&unit| (unit, derived_inverse::<F> as Callback))
+        .collect();
+    t.extend(explicit.iter().copied());
+    t
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use crate::Category;
+
+    #[test]
+    fn derived_inverses_undo_the_forward_conversion() {
+        for category in Category::ALL {
+            if category == Category::Energy {
+                continue;
+            }
+            for (unit, to_base) in category.to_base_map() {
+                if category.is_affine(unit) {
+                    continue;
+                }
+                let from_base = category.to_unit_map()[unit];
+                for v in [1.0, -3.5, 1e-20, 6.022e23] {
+                    let round_trip = from_base(to_base(v, unit).unwrap(), unit).unwrap();
+                    assert_relative_eq!(round_trip, v, max_relative = 1e-12);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! This module offers an iterator adaptor converting a stream of values between two units.
+
+use crate::dispatch::Conversion;
+use crate::ConversionError;
+
+/// Extends iterators over f64 values with [`ConvertExt::convert`].
+pub trait ConvertExt: Iterator<Item = f64> + Sized {
+    /// Converts every value from `from` to `to`, e.g.
+    /// `values.iter().copied().convert("bohr", "ang")`.
+    ///
+    /// The units are resolved once when the first value is polled, so an empty iterator never
+    /// looks them up. If they can not be converted into each other, every value yields the error.
+    fn convert<'a>(self, from: &'a str, to: &'a str) -> Convert<'a, Self> {
+        Convert {
+            values: self,
+            from,
+            to,
+            conversion: None,
+        }
+    }
+}
+
+impl<I: Iterator<Item = f64>> ConvertExt for I {}
+
+/// Iterator returned by [`ConvertExt::convert`].
+pub struct Convert<'a, I> {
+    values: I,
+    from: &'a str,
+    to: &'a str,
+    conversion: Option<Result<Conversion, ConversionError>>,
+}
+
+impl<I: Iterator<Item = f64>> Iterator for Convert<'_, I> {
+    type Item = Result<f64, ConversionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.values.next()?;
+        let (from, to) = (self.from, self.to);
+        match self
+            .conversion
+            .get_or_insert_with(|| Conversion::resolve(from, to))
+        {
+            Ok(conversion) => Some(conversion.apply(value)),
+            Err(e) => Some(Err(e.clone())),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::codata::expected;
+
+    #[test]
+    fn converts_vectors_and_lazy_iterators() {
+        let values = vec![1.0, 2.0, -0.5];
+        let converted: Vec<f64> = values
+            .iter()
+            .copied()
+            .convert("bohr", "ang")
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(converted.len(), 3);
+        assert_relative_eq!(converted[1], 2.0 * expected::BOHR_RADIUS_IN_ANGSTROM);
+
+        let mut celsius = (0..).map(f64::from).convert("K", "degC");
+        assert_relative_eq!(celsius.next().unwrap().unwrap(), -273.15);
+        assert_relative_eq!(celsius.nth(272).unwrap().unwrap(), -0.15, epsilon = 1e-12);
+
+        assert_eq!(core::iter::empty().convert("parsnip", "bohr").count(), 0);
+        let unknown: Vec<_> = values.into_iter().convert("parsnip", "bohr").collect();
+        assert_eq!(unknown.len(), 3);
+        assert!(unknown
+            .iter()
+            .all(|r| *r == Err(ConversionError::UnknownUnit("parsnip".to_string()))));
+    }
+}
@@ -1,84 +1,553 @@
 //* This module offers access to length conversion functions.
+//* The functions are generic over f32 and f64; the conversion maps hold the f64 variants.
 //* Conversion factors were taken from [here](https://physics.nist.gov/cuu/Constants/)
 
-use lazy_static::lazy_static;
-use std::collections::HashMap;
+use alloc::string::ToString;
 
-const BOHR_RADIUS_TO_METRES: f64 = 5.291_772_109_03e-11;
-const CENTI: f64 = 1e-2;
-const MILLI: f64 = 1e-3;
-const MIKRO: f64 = 1e-6;
-const NANO: f64 = 1e-9;
-const PICO: f64 = 1e-12;
+use num_traits::Float;
 
-type Callback = fn(f64, prefix: &str) -> f64;
+use crate::category::{convert_within, Callback, UnitCategory};
+use crate::codata;
+use crate::collections::Map;
+use crate::float::cast;
+use crate::inverse::{invert, Forward};
+use crate::lookup::get_converter;
+use crate::prefix::{normalize_micro, prefixed_factor};
+use crate::sync::OnceLock;
+use crate::ConversionError;
+
+pub const BOHR_RADIUS_TO_METRES: f64 = codata::BOHR_RADIUS_TO_METRES;
+pub const BOHR_RADIUS_TO_ANGSTROM: f64 = codata::BOHR_RADIUS_TO_ANGSTROM;
+pub(crate) const CENTI: f64 = 1e-2;
+pub const ANGSTROEM: f64 = 1e-10;
+pub const INCH_TO_METRES: f64 = 0.0254;
+pub const FOOT_TO_METRES: f64 = 0.3048;
+pub const YARD_TO_METRES: f64 = 0.9144;
+pub const MILE_TO_METRES: f64 = 1609.344;
+pub const ASTRONOMICAL_UNIT_TO_METRES: f64 = 1.495_978_707e11;
+pub const LIGHT_YEAR_TO_METRES: f64 = 9.460_730_472_580_8e15;
+pub const PARSEC_TO_METRES: f64 = 3.085_677_581e16;
 
 /// Unit function. Will simply return the value.
-fn unity(v: f64, prefix: &str) -> f64 {
-    v
+fn unity<T: Float>(v: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(v)
 }
 
 /// Function to convert bohr to metres.
 /// # Arguments
 ///  * `b` - value in bohr to convert
-///  * `prefix` - can be one of [`pm`,`nm`, `mu`, `mm`, `cm`, `m`]. Will scale the value in metres to
-///  pico-, nano-, micro-, milli-, centi-metres or not at all
+///  * `prefix` - an SI-prefixed metre like `fm`, `nm`, `cm`, `m` or `km`, or `ang`. Will scale
+///    the value in metres to the prefixed metres or angstroem
+///
+pub fn bohr_to_metres<T: Float>(b: T, prefix: &str) -> Result<T, ConversionError> {
+    Ok(b * cast(BOHR_RADIUS_TO_METRES) / metre_prefix_factor(prefix)?)
+}
+
+/// Function to convert metres to bohr.
+/// # Arguments
+///  * `v` - value in metres to convert
+///  * `prefix` - an SI-prefixed metre like `fm`, `nm`, `cm`, `m` or `km`, or `ang`. Gives
+///    whether the value is in prefixed metres or angstroem
 ///
-fn bohr_to_metres(b: f64, prefix: &str) -> f64 {
-    match prefix {
-        "pm" => b * BOHR_RADIUS_TO_METRES / PICO,
-        "nm" => b * BOHR_RADIUS_TO_METRES / NANO,
-        "mu" => b * BOHR_RADIUS_TO_METRES / MIKRO,
-        "mm" => b * BOHR_RADIUS_TO_METRES / MILLI,
-        "cm" => b * BOHR_RADIUS_TO_METRES / CENTI,
-        "m" => b * BOHR_RADIUS_TO_METRES,
-        _ => panic!("Unkown prefix {}", prefix),
+pub fn metres_to_bohr<T: Float>(v: T, prefix: &str) -> Result<T, ConversionError> {
+    Ok(v * metre_prefix_factor(prefix)? / cast(BOHR_RADIUS_TO_METRES))
+}
+
+/// Returns the size of the prefixed metre unit in metres or `UnknownPrefix` for an unsupported
+/// prefix. Every SI prefix is accepted, e.g. `fm`, `nm`, `km` or `Gm`. Angstroem is accepted as a
+/// prefix so that values can be expressed in it directly. Micrometres may be spelled `µm`, `μm`,
+/// `um`, `mu` or `micron`.
+fn metre_prefix_factor<T: Float>(prefix: &str) -> Result<T, ConversionError> {
+    if prefix == "ang" {
+        return Ok(cast(ANGSTROEM));
+    }
+    prefixed_factor(&normalize_micro(prefix), "m")
+        .map(cast)
+        .ok_or_else(|| ConversionError::UnknownPrefix(prefix.to_string()))
+}
+
+/// Returns the size of the prefixed angstroem or metre unit in angstroem or `UnknownPrefix` for
+/// an unsupported prefix. The empty prefix, `ang` and `Å` are the angstroem, an SI prefix in front
+/// of them like `mang` or `mÅ` scales it, and every prefixed metre like `pm` is accepted as well.
+fn angstroem_prefix_factor<T: Float>(prefix: &str) -> Result<T, ConversionError> {
+    if prefix.is_empty() {
+        return Ok(T::one());
+    }
+    match prefixed_factor(prefix, "ang").or_else(|| prefixed_factor(prefix, "Å")) {
+        Some(factor) => Ok(cast(factor)),
+        None => Ok(metre_prefix_factor::<T>(prefix)? / cast(ANGSTROEM)),
     }
 }
 
 /// Function to convert bohr to angstroem
 /// # Arguments
 ///  * `b` - value in bohr to convert
-///  * `prefix` - default: 'ang'. Can pass anything you like.
+///  * `prefix` - default: 'ang'. A prefixed angstroem like `mang` or an SI-prefixed metre like
+///    `pm` gives the result in that unit instead
+///
+pub fn bohr_to_ang<T: Float>(b: T, prefix: &str) -> Result<T, ConversionError> {
+    Ok(b * cast(BOHR_RADIUS_TO_ANGSTROM) / angstroem_prefix_factor(prefix)?)
+}
+
+/// Function to convert angstroem to bohr
+/// # Arguments
+///  * `v` - value in angstroem to convert
+///  * `prefix` - default: 'ang'. A prefixed angstroem like `mang` or an SI-prefixed metre like
+///    `pm` gives whether the value is in that unit instead
+///
+pub fn ang_to_bohr<T: Float>(v: T, prefix: &str) -> Result<T, ConversionError> {
+    Ok(v * angstroem_prefix_factor(prefix)? / cast(BOHR_RADIUS_TO_ANGSTROM))
+}
+
+/// Converts bohr to metres without a prefix lookup, so it can be used in const contexts.
+pub const fn bohr_to_metres_const(b: f64) -> f64 {
+    b * BOHR_RADIUS_TO_METRES
+}
+
+/// Converts metres to bohr without a prefix lookup, so it can be used in const contexts.
+pub const fn metres_to_bohr_const(v: f64) -> f64 {
+    v / BOHR_RADIUS_TO_METRES
+}
+
+/// Converts bohr to angstroem without a prefix lookup, so it can be used in const contexts.
+pub const fn bohr_to_ang_const(b: f64) -> f64 {
+    b * BOHR_RADIUS_TO_ANGSTROM
+}
+
+/// Converts angstroem to bohr without a prefix lookup, so it can be used in const contexts.
+pub const fn ang_to_bohr_const(v: f64) -> f64 {
+    v / BOHR_RADIUS_TO_ANGSTROM
+}
+
+/// Function to convert metres to angstroem.
+pub fn metres_to_ang<T: Float>(v: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(v / cast(ANGSTROEM))
+}
+
+/// Function to convert angstroem to metres.
+pub fn ang_to_metres<T: Float>(v: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(v * cast(ANGSTROEM))
+}
+
+/// Function to scale a value given in (prefixed) metres to metres.
+/// # Arguments
+///  * `v` - value to convert
+///  * `prefix` - an SI-prefixed metre like `fm`, `nm`, `cm`, `m` or `km`, or `ang`.
+///    Gives the unit of the value.
+///
+pub fn prefixed_metres_to_metres<T: Float>(v: T, prefix: &str) -> Result<T, ConversionError> {
+    Ok(v * metre_prefix_factor(prefix)?)
+}
+
+/// Function to scale a value in metres to (prefixed) metres.
+/// # Arguments
+///  * `v` - value in metres to convert
+///  * `prefix` - an SI-prefixed metre like `fm`, `nm`, `cm`, `m` or `km`, or `ang`.
+///    Gives the unit of the result.
+///
+pub fn metres_to_prefixed_metres<T: Float>(v: T, prefix: &str) -> Result<T, ConversionError> {
+    Ok(v / metre_prefix_factor(prefix)?)
+}
+
+/// Returns the size of the imperial unit in metres. The definitions are exact.
+fn imperial_factor<T: Float>(unit: &str) -> Result<T, ConversionError> {
+    match unit {
+        "in" => Ok(cast(INCH_TO_METRES)),
+        "ft" => Ok(cast(FOOT_TO_METRES)),
+        "yd" => Ok(cast(YARD_TO_METRES)),
+        "mi" => Ok(cast(MILE_TO_METRES)),
+        _ => Err(ConversionError::UnknownUnit(unit.to_string())),
+    }
+}
+
+/// Function to convert an imperial length to metres.
+/// # Arguments
+///  * `v` - value to convert
+///  * `prefix` - can be one of [`in`, `ft`, `yd`, `mi`]. Gives the unit of the value.
+///
+pub fn imperial_to_metres<T: Float>(v: T, prefix: &str) -> Result<T, ConversionError> {
+    Ok(v * imperial_factor(prefix)?)
+}
+
+/// Function to convert metres to an imperial length.
+/// # Arguments
+///  * `v` - value in metres to convert
+///  * `prefix` - can be one of [`in`, `ft`, `yd`, `mi`]. Gives the unit of the result.
 ///
-fn bohr_to_ang(b: f64, prefix: &str) -> f64 {
-    bohr_to_metres(b, "nm") * 10.0
+pub fn metres_to_imperial<T: Float>(v: T, prefix: &str) -> Result<T, ConversionError> {
+    Ok(v / imperial_factor(prefix)?)
+}
+
+/// Function to convert bohr to plain metres, ignoring the prefix.
+pub fn bohr_to_plain_metres<T: Float>(b: T, _prefix: &str) -> Result<T, ConversionError> {
+    bohr_to_metres(b, "m")
+}
+
+/// Function to convert plain metres to bohr, ignoring the prefix.
+pub fn plain_metres_to_bohr<T: Float>(v: T, _prefix: &str) -> Result<T, ConversionError> {
+    metres_to_bohr(v, "m")
+}
+
+/// Function to convert a wavenumber in cm⁻¹ to the wavelength in nm, λ = 1e7 / ν̃.
+/// This is a reciprocal, not a multiplicative conversion; a zero wavenumber returns infinity.
+pub fn rcm_to_nm<T: Float>(v: T, prefix: &str) -> Result<T, ConversionError> {
+    crate::energy::rcm_2_nm(v, prefix)
+}
+
+/// Function to convert a wavelength in nm to the wavenumber in cm⁻¹, ν̃ = 1e7 / λ.
+/// This is a reciprocal, not a multiplicative conversion; a zero wavelength returns infinity.
+pub fn nm_to_rcm<T: Float>(v: T, prefix: &str) -> Result<T, ConversionError> {
+    crate::energy::nm_2_rcm(v, prefix)
+}
+
+/// Function to convert astronomical units to metres.
+pub fn au_to_metres<T: Float>(v: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(v * cast(ASTRONOMICAL_UNIT_TO_METRES))
+}
+
+/// Function to convert metres to astronomical units.
+pub fn metres_to_au<T: Float>(v: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(v / cast(ASTRONOMICAL_UNIT_TO_METRES))
+}
+
+/// Function to convert light-years to metres.
+pub fn ly_to_metres<T: Float>(v: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(v * cast(LIGHT_YEAR_TO_METRES))
+}
+
+/// Function to convert metres to light-years.
+pub fn metres_to_ly<T: Float>(v: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(v / cast(LIGHT_YEAR_TO_METRES))
+}
+
+/// Function to convert parsec to metres.
+pub fn pc_to_metres<T: Float>(v: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(v * cast(PARSEC_TO_METRES))
 }
 
-lazy_static! {
-    pub static ref CONVERT_BOHR_TO_METRES: HashMap<&'static str, Callback> = {
-        let mut t = HashMap::new();
+/// Function to convert metres to parsec.
+pub fn metres_to_pc<T: Float>(v: T, _prefix: &str) -> Result<T, ConversionError> {
+    Ok(v / cast(PARSEC_TO_METRES))
+}
+
+pub fn convert_bohr_to_metres() -> &'static Map<&'static str, Callback> {
+    static MAP: OnceLock<Map<&'static str, Callback>> = OnceLock::new();
+    MAP.get_or_init(|| {
+        let mut t = Map::new();
         t.insert("bohr", unity as Callback);
         t.insert("m", bohr_to_metres as Callback);
+        t.insert("fm", bohr_to_metres as Callback);
+        t.insert("ang", bohr_to_metres as Callback);
         t
-    };
-    pub static ref CONVERT_BOHR_TO_ANG: HashMap<&'static str, Callback> = {
-        let mut t = HashMap::new();
+    })
+}
+
+pub fn convert_bohr_to_ang() -> &'static Map<&'static str, Callback> {
+    static MAP: OnceLock<Map<&'static str, Callback>> = OnceLock::new();
+    MAP.get_or_init(|| {
+        let mut t = Map::new();
         t.insert("bohr", unity as Callback);
         t.insert("ang", bohr_to_ang as Callback);
         t
-    };
+    })
+}
+
+pub fn convert_to_bohr_from() -> &'static Map<&'static str, Callback> {
+    static MAP: OnceLock<Map<&'static str, Callback>> = OnceLock::new();
+    MAP.get_or_init(|| {
+        let mut t = Map::new();
+        t.insert("bohr", unity as Callback);
+        t.insert("m", metres_to_bohr as Callback);
+        t.insert("cm", metres_to_bohr as Callback);
+        t.insert("mm", metres_to_bohr as Callback);
+        t.insert("mu", metres_to_bohr as Callback);
+        t.insert("µm", metres_to_bohr as Callback);
+        t.insert("μm", metres_to_bohr as Callback);
+        t.insert("um", metres_to_bohr as Callback);
+        t.insert("micron", metres_to_bohr as Callback);
+        t.insert("nm", metres_to_bohr as Callback);
+        t.insert("pm", metres_to_bohr as Callback);
+        t.insert("fm", metres_to_bohr as Callback);
+        t.insert("ang", ang_to_bohr as Callback);
+        t
+    })
+}
+
+pub fn convert_to_metres_from() -> &'static Map<&'static str, Callback> {
+    static MAP: OnceLock<Map<&'static str, Callback>> = OnceLock::new();
+    MAP.get_or_init(|| {
+        let mut t = Map::new();
+        t.insert("bohr", bohr_to_plain_metres as Callback);
+        t.insert("m", prefixed_metres_to_metres as Callback);
+        t.insert("cm", prefixed_metres_to_metres as Callback);
+        t.insert("mm", prefixed_metres_to_metres as Callback);
+        t.insert("mu", prefixed_metres_to_metres as Callback);
+        t.insert("µm", prefixed_metres_to_metres as Callback);
+        t.insert("μm", prefixed_metres_to_metres as Callback);
+        t.insert("um", prefixed_metres_to_metres as Callback);
+        t.insert("micron", prefixed_metres_to_metres as Callback);
+        t.insert("nm", prefixed_metres_to_metres as Callback);
+        t.insert("pm", prefixed_metres_to_metres as Callback);
+        t.insert("fm", prefixed_metres_to_metres as Callback);
+        t.insert("ang", ang_to_metres as Callback);
+        t.insert("in", imperial_to_metres as Callback);
+        t.insert("ft", imperial_to_metres as Callback);
+        t.insert("yd", imperial_to_metres as Callback);
+        t.insert("mi", imperial_to_metres as Callback);
+        t.insert("AU", au_to_metres as Callback);
+        t.insert("ly", ly_to_metres as Callback);
+        t.insert("pc", pc_to_metres as Callback);
+        t
+    })
+}
+
+/// Gives the derived inverse map access to the forward map.
+struct ToMetres;
+
+impl Forward for ToMetres {
+    fn forward() -> &'static Map<&'static str, Callback> {
+        convert_to_metres_from()
+    }
+}
+
+pub fn convert_metres_to() -> &'static Map<&'static str, Callback> {
+    static MAP: OnceLock<Map<&'static str, Callback>> = OnceLock::new();
+    MAP.get_or_init(|| invert::<ToMetres>(&[]))
+}
+
+/// The length category, whose units are converted through metres.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Length;
+
+impl UnitCategory for Length {
+    fn to_base(value: f64, unit: &str) -> Result<f64, ConversionError> {
+        get_converter(convert_to_metres_from(), unit)?(value, unit)
+    }
+
+    fn from_base(value: f64, unit: &str) -> Result<f64, ConversionError> {
+        get_converter(convert_metres_to(), unit)?(value, unit)
+    }
+}
+
+/// Converts `value` from the length unit `from` to the length unit `to` by going through metres.
+/// Returns `UnknownUnit` if either unit is not registered instead of panicking.
+pub fn try_convert(value: f64, from: &str, to: &str) -> Result<f64, ConversionError> {
+    convert_within::<Length>(value, from, to)
 }
 
 #[cfg(test)]
 mod unit_tests {
-    use crate::length::CONVERT_BOHR_TO_ANG;
+    use alloc::string::ToString;
+    use approx::assert_relative_eq;
 
-    use super::CONVERT_BOHR_TO_METRES;
+    use super::{
+        ang_to_bohr, ang_to_bohr_const, bohr_to_ang, bohr_to_ang_const, bohr_to_metres,
+        convert_metres_to, convert_to_bohr_from, convert_to_metres_from, metres_to_bohr, nm_to_rcm,
+        rcm_to_nm, try_convert, ConversionError, ANGSTROEM, BOHR_RADIUS_TO_ANGSTROM,
+        BOHR_RADIUS_TO_METRES,
+    };
+    use crate::codata::expected;
     #[test]
     fn convert_bohr_to_metres() {
-        let converted = CONVERT_BOHR_TO_METRES["m"](1.0_f64, "m");
-        let expected = 5.291_772_109_03e-11;
-        assert_eq!(converted, expected);
-        let converted = CONVERT_BOHR_TO_METRES["m"](1.0_f64, "nm");
-        let expected = 5.291_772_109_03e-2;
-        assert_eq!(converted, expected);
+        let converted = super::convert_bohr_to_metres()["m"](1.0_f64, "m").unwrap();
+        assert_eq!(converted, expected::BOHR_RADIUS_IN_METRES);
+        let converted = super::convert_bohr_to_metres()["m"](1.0_f64, "nm").unwrap();
+        assert_relative_eq!(converted, expected::BOHR_RADIUS_IN_NANOMETRES);
+    }
+
+    #[test]
+    fn bohr_to_si_prefixed_metres() {
+        assert_relative_eq!(
+            bohr_to_metres(1.0, "fm").unwrap(),
+            expected::BOHR_RADIUS_IN_FEMTOMETRES
+        );
+        assert_relative_eq!(
+            bohr_to_metres(1.0, "Gm").unwrap(),
+            expected::BOHR_RADIUS_IN_GIGAMETRES
+        );
+        assert_relative_eq!(
+            bohr_to_metres(1.0, "km").unwrap(),
+            expected::BOHR_RADIUS_IN_KILOMETRES
+        );
+        assert_relative_eq!(
+            bohr_to_metres(1.0, "am").unwrap(),
+            expected::BOHR_RADIUS_IN_ATTOMETRES
+        );
+        assert_relative_eq!(
+            metres_to_bohr(1.0, "dam").unwrap(),
+            expected::BOHR_RADII_PER_DECAMETRE,
+            max_relative = 1e-10
+        );
+        assert_eq!(
+            bohr_to_metres(1.0, "xm"),
+            Err(ConversionError::UnknownPrefix("xm".to_string()))
+        );
     }
 
     #[test]
     fn convert_bohr_to_ang() {
-        let converted = CONVERT_BOHR_TO_ANG["ang"](1.0_f64, "ang");
-        let expected = 5.291_772_109_03e-1;
-        assert_eq!(converted, expected);
+        let converted = super::convert_bohr_to_ang()["ang"](1.0_f64, "ang").unwrap();
+        assert_eq!(converted, expected::BOHR_RADIUS_IN_ANGSTROM);
+    }
+
+    #[test]
+    fn convert_to_bohr() {
+        let converted = convert_to_bohr_from()["m"](0.529_177e-10, "m").unwrap();
+        assert_relative_eq!(converted, 1.0, max_relative = 1e-6);
+        let nm = expected::BOHR_RADIUS_IN_NANOMETRES;
+        let converted = convert_to_bohr_from()["nm"](nm, "nm").unwrap();
+        assert_relative_eq!(converted, 1.0);
+        let ang = expected::BOHR_RADIUS_IN_ANGSTROM;
+        let converted = convert_to_bohr_from()["ang"](ang, "ang").unwrap();
+        assert_relative_eq!(converted, 1.0);
+    }
+
+    #[test]
+    fn convert_imperial_units() {
+        let mile_in_metres = convert_to_metres_from()["mi"](1.0, "mi").unwrap();
+        assert_eq!(mile_in_metres, 1609.344);
+        assert_eq!(
+            convert_metres_to()["yd"](mile_in_metres, "yd").unwrap(),
+            1760.0
+        );
+        let foot_in_metres = convert_to_metres_from()["ft"](1.0, "ft").unwrap();
+        assert_relative_eq!(
+            convert_metres_to()["in"](foot_in_metres, "in").unwrap(),
+            12.0
+        );
+        assert_relative_eq!(
+            convert_metres_to()["ft"](1.0, "ft").unwrap(),
+            3.280_839_895_013_123
+        );
+    }
+
+    #[test]
+    fn convert_astronomical_units() {
+        let parsec_in_metres = convert_to_metres_from()["pc"](1.0, "pc").unwrap();
+        let parsec_in_au = convert_metres_to()["AU"](parsec_in_metres, "AU").unwrap();
+        assert_relative_eq!(parsec_in_au, 206_264.8, max_relative = 1e-6);
+        let back = convert_metres_to()["pc"](
+            convert_to_metres_from()["AU"](parsec_in_au, "AU").unwrap(),
+            "pc",
+        )
+        .unwrap();
+        assert_relative_eq!(back, 1.0, max_relative = 1e-12);
+        let light_year =
+            convert_metres_to()["ly"](convert_to_metres_from()["ly"](1.0, "ly").unwrap(), "ly")
+                .unwrap();
+        assert_relative_eq!(light_year, 1.0);
+    }
+
+    #[test]
+    fn convert_bohr_to_ang_directly() {
+        for b in [1.0_f64, -3.5, 1e6] {
+            let direct = super::convert_bohr_to_metres()["ang"](b, "ang").unwrap();
+            let indirect = bohr_to_metres(b, "nm").unwrap() * 10.0;
+            assert_relative_eq!(direct, indirect);
+            assert_relative_eq!(
+                direct,
+                super::convert_bohr_to_ang()["ang"](b, "ang").unwrap()
+            );
+        }
+        let converted = super::convert_bohr_to_metres()["fm"](1.0_f64, "fm").unwrap();
+        assert_relative_eq!(converted, expected::BOHR_RADIUS_IN_FEMTOMETRES);
+        let metres = convert_to_metres_from()["ang"](1.0, "ang").unwrap();
+        assert_eq!(metres, 1e-10);
+        assert_relative_eq!(convert_metres_to()["ang"](metres, "ang").unwrap(), 1.0);
+    }
+
+    #[test]
+    fn direct_angstrom_factor_matches_the_metre_route() {
+        assert_relative_eq!(BOHR_RADIUS_TO_ANGSTROM, BOHR_RADIUS_TO_METRES / ANGSTROEM);
+        for b in [0.0_f64, 1.0, -3.5, 1e6] {
+            let direct = bohr_to_ang(b, "ang").unwrap();
+            assert_relative_eq!(direct, bohr_to_metres(b, "nm").unwrap() * 10.0);
+            assert_relative_eq!(direct, bohr_to_metres(b, "ang").unwrap());
+            assert_relative_eq!(direct, bohr_to_ang_const(b));
+            assert_relative_eq!(ang_to_bohr(direct, "ang").unwrap(), b);
+            assert_relative_eq!(ang_to_bohr_const(direct), b);
+        }
+    }
+
+    #[test]
+    fn prefixed_angstroem_and_metres() {
+        assert_eq!(bohr_to_ang(1.0, "ang"), Ok(BOHR_RADIUS_TO_ANGSTROM));
+        assert_eq!(bohr_to_ang(1.0, "Å"), Ok(BOHR_RADIUS_TO_ANGSTROM));
+        assert_eq!(bohr_to_ang(1.0, ""), Ok(BOHR_RADIUS_TO_ANGSTROM));
+        let (mang, pm) = (
+            expected::BOHR_RADIUS_IN_MILLIANGSTROM,
+            expected::BOHR_RADIUS_IN_PICOMETRES,
+        );
+        assert_relative_eq!(bohr_to_ang(1.0, "mang").unwrap(), mang);
+        assert_relative_eq!(bohr_to_ang(1.0, "pm").unwrap(), pm);
+        assert_relative_eq!(ang_to_bohr(pm, "pm").unwrap(), 1.0);
+        assert_eq!(
+            bohr_to_ang(1.0, "parsnip"),
+            Err(ConversionError::UnknownPrefix("parsnip".to_string()))
+        );
+    }
+
+    #[test]
+    fn unknown_units_and_prefixes_are_errors() {
+        assert_relative_eq!(
+            try_convert(1.0, "bohr", "ang").unwrap(),
+            expected::BOHR_RADIUS_IN_ANGSTROM
+        );
+        assert_relative_eq!(try_convert(1.0, "mi", "ft").unwrap(), 5280.0);
+        assert_eq!(
+            try_convert(1.0, "parsnip", "m"),
+            Err(ConversionError::UnknownUnit("parsnip".to_string()))
+        );
+        assert_eq!(
+            super::convert_bohr_to_metres()["m"](1.0, "xm"),
+            Err(ConversionError::UnknownPrefix("xm".to_string()))
+        );
+    }
+
+    #[test]
+    fn convert_in_single_precision() {
+        let converted = bohr_to_metres::<f32>(1.0, "m").unwrap();
+        assert_relative_eq!(converted, 5.291_772e-11_f32, max_relative = 1e-6);
+        let converted = bohr_to_metres(1.0_f32, "ang").unwrap();
+        assert_relative_eq!(converted, 0.529_177_2_f32, max_relative = 1e-6);
+        let back = metres_to_bohr(converted, "ang").unwrap();
+        assert_relative_eq!(back, 1.0_f32, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn micro_prefix_spellings() {
+        let bohr = expected::BOHR_RADIUS_IN_MICROMETRES;
+        for micro in ["µm", "μm", "um", "mu", "micron"] {
+            assert_relative_eq!(bohr_to_metres(1.0, micro).unwrap(), bohr);
+            assert_relative_eq!(try_convert(1.0, micro, "nm").unwrap(), 1_000.0);
+        }
+    }
+
+    #[test]
+    fn convert_in_const_context() {
+        const BOHR_IN_ANG: f64 = super::bohr_to_ang_const(1.0);
+        const ANG_IN_BOHR: f64 = super::ang_to_bohr_const(BOHR_IN_ANG);
+        assert_eq!(BOHR_IN_ANG, super::bohr_to_ang(1.0, "ang").unwrap());
+        assert_relative_eq!(ANG_IN_BOHR, 1.0);
+        assert_eq!(
+            super::bohr_to_metres_const(2.0),
+            bohr_to_metres(2.0, "m").unwrap()
+        );
+        assert_eq!(
+            super::metres_to_bohr_const(1e-10),
+            metres_to_bohr(1.0, "ang").unwrap()
+        );
+    }
+
+    #[test]
+    fn wavenumbers_and_wavelengths_are_reciprocal() {
+        assert_relative_eq!(rcm_to_nm(10_000.0, "").unwrap(), 1000.0);
+        assert_relative_eq!(nm_to_rcm(1000.0, "").unwrap(), 10_000.0);
+        for wavenumber in [1.0, 2_349.1, 10_000.0] {
+            let wavelength = rcm_to_nm(wavenumber, "").unwrap();
+            assert_relative_eq!(nm_to_rcm(wavelength, "").unwrap(), wavenumber);
+        }
+        assert_eq!(rcm_to_nm(0.0, "").unwrap(), f64::INFINITY);
+        assert_eq!(nm_to_rcm(-0.0, "").unwrap(), f64::INFINITY);
     }
 }
@@ -1,2 +1,114 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod acceleration;
+pub mod affine;
+pub mod amount;
+pub mod angle;
+pub mod area;
+pub mod atomic_units;
+mod cache;
+pub mod category;
+pub mod charge;
+mod checked;
+mod codata;
+mod collections;
+pub mod compound;
+#[cfg(feature = "const-table")]
+mod const_table;
+pub mod converter;
+pub mod data;
+pub mod dimension;
+pub mod dipole;
+mod dispatch;
+pub mod electric_field;
 pub mod energy;
+pub mod error;
+pub mod export;
+mod float;
+pub mod force;
+pub mod format;
+pub mod frequency;
+mod graph;
+pub mod ideal_gas;
+pub mod info;
+mod inverse;
+pub mod iter;
 pub mod length;
+mod lookup;
+pub mod magnetic_field;
+pub mod magnetic_moment;
+pub mod mass;
+pub mod measurement;
+pub mod momentum;
+pub mod natural_units;
+pub mod photometry;
+pub mod photon;
+pub mod pipeline;
+pub mod planck;
+pub mod power;
+pub mod precise;
+mod prefix;
+pub mod pressure;
+pub mod quantity;
+mod registry;
+pub mod solid_angle;
+pub mod speed;
+mod sync;
+mod table;
+pub mod temperature;
+pub mod time;
+pub mod typed;
+pub mod unit;
+#[cfg(feature = "uom")]
+pub mod uom_interop;
+pub mod viscosity;
+
+pub use category::{
+    categories, convert_within, entries, supported_units, Callback, Category, UnitCategory,
+};
+pub use checked::{checked_convert, CheckedConvert};
+pub use compound::{convert_compound, parse_compound, CompoundUnit};
+#[cfg(feature = "const-table")]
+pub use const_table::lookup_const;
+pub use converter::Converter;
+pub use dimension::Dimension;
+pub use dispatch::{conversion_factor, convert, convert_slice, convert_slice_mut};
+pub use error::ConversionError;
+pub use export::{conversion_rows, export_table, ConversionRow};
+pub use graph::{ConversionGraph, Edge};
+pub use info::{unit_info, unit_info_in, UnitInfo};
+pub use iter::ConvertExt;
+pub use lookup::{get_converter, lookup, TryGetConverter};
+pub use measurement::Measurement;
+pub use pipeline::{ConversionPipeline, Pipeline};
+pub use quantity::{parse_quantity, Quantity};
+pub use registry::{ConversionFn, UnitRegistry};
+pub use unit::{convert_units, Unit};
+
+/// Exercises the core conversions on the `no_std` code paths, i.e. the `BTreeMap` tables and the
+/// atomic once cell. Run with `cargo test --no-default-features`.
+#[cfg(all(test, not(feature = "std")))]
+mod no_std_tests {
+    use alloc::string::ToString;
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn core_conversions_without_std() {
+        assert_relative_eq!(
+            convert(1.0, "bohr", "ang").unwrap(),
+            crate::length::BOHR_RADIUS_TO_ANGSTROM
+        );
+        assert_relative_eq!(convert(2.0, "eV", "rcm").unwrap(), 16_131.087_874);
+        assert_relative_eq!(convert(0.0, "degC", "K").unwrap(), 273.15);
+        assert_relative_eq!(length::bohr_to_metres(1.0_f32, "ang").unwrap(), 0.529_177_2);
+        assert_eq!(
+            convert(1.0, "parsnip", "bohr"),
+            Err(ConversionError::UnknownUnit("parsnip".to_string()))
+        );
+        assert!(supported_units(Category::Length).contains(&"bohr"));
+    }
+}
@@ -0,0 +1,135 @@
+//! This module offers a forgiving unit lookup for user supplied unit names.
+
+use alloc::string::ToString;
+
+use crate::category::Callback;
+use crate::collections::Map;
+use crate::{Category, ConversionError};
+
+/// Returns the callback of `unit` in a conversion map like
+/// [`crate::length::convert_bohr_to_metres`], or `UnknownUnit` if the map does not know it.
+///
+/// Indexing the map with `[]` panics on a missing unit and remains available where the unit is
+/// known to be registered.
+pub fn get_converter(
+    map: &Map<&'static str, Callback>,
+    unit: &str,
+) -> Result<Callback, ConversionError> {
+    map.get(unit)
+        .copied()
+        .ok_or_else(|| ConversionError::UnknownUnit(unit.to_string()))
+}
+
+/// Non-panicking access to the conversion maps, e.g.
+/// `convert_bohr_to_metres().try_get("xyz")` returns `UnknownUnit("xyz")`.
+pub trait TryGetConverter {
+    /// Returns the callback of `unit` like [`get_converter`].
+    fn try_get(&self, unit: &str) -> Result<Callback, ConversionError>;
+}
+
+impl TryGetConverter for Map<&'static str, Callback> {
+    fn try_get(&self, unit: &str) -> Result<Callback, ConversionError> {
+        get_converter(self, unit)
+    }
+}
+
+/// First letters whose case changes the meaning of a unit, e.g. milli- and mega- or pico- and
+/// peta-. Units starting with one of them are only matched if the first letter agrees.
+const CASE_SENSITIVE_PREFIXES: [char; 4] = ['m', 'M', 'p', 'P'];
+
+/// Returns whether `input` is `key` spelled with a different capitalisation that can not change
+/// the meaning of the unit.
+fn is_case_variant(key: &str, input: &str) -> bool {
+    let first_letters_agree = match (key.chars().next(), input.chars().next()) {
+        (Some(k), Some(i)) => k == i || !CASE_SENSITIVE_PREFIXES.contains(&k),
+        _ => false,
+    };
+    first_letters_agree && key.to_lowercase() == input.to_lowercase()
+}
+
+/// Looks up `unit` in the conversion map of `category`, ignoring surrounding whitespace and,
+/// where it is unambiguous, the capitalisation. `"EV"`, `" ev "` and `"Bohr"` are found as
+/// `eV` and `bohr`, while `"mw"` is not taken for `MW`. Data sizes are matched exactly, as the
+/// case of `b` and `B` tells bits from bytes, so `"b"` or `"gb"` are not found.
+///
+/// Returns the registered unit key together with the callback converting to the base unit of the
+/// category. The key has to be passed as prefix to the callback. Returns `UnknownUnit` if no or
+/// more than one unit matches.
+pub fn lookup(category: Category, unit: &str) -> Result<(&'static str, Callback), ConversionError> {
+    let map = category.to_base_map();
+    let unit = unit.trim();
+    if let Some((key, callback)) = map.get_key_value(unit) {
+        return Ok((*key, *callback));
+    }
+    if category == Category::Data {
+        return Err(ConversionError::UnknownUnit(unit.to_string()));
+    }
+    let mut matches = map.iter().filter(|(key, _)| is_case_variant(key, unit));
+    match (matches.next(), matches.next()) {
+        (Some((key, callback)), None) => Ok((*key, *callback)),
+        _ => Err(ConversionError::UnknownUnit(unit.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::codata::expected;
+
+    #[test]
+    fn common_miscapitalizations() {
+        for spelling in ["EV", "ev", " eV ", "Ev"] {
+            let (key, callback) = lookup(Category::Energy, spelling).unwrap();
+            assert_eq!(key, "eV");
+            assert_eq!(callback(1.0, key), Ok(1.0));
+        }
+        assert_eq!(lookup(Category::Length, "Bohr").unwrap().0, "bohr");
+        assert_eq!(lookup(Category::Length, " ang ").unwrap().0, "ang");
+        assert_eq!(lookup(Category::Frequency, "MHZ").unwrap().0, "MHz");
+        let (key, callback) = lookup(Category::Energy, "HARTREE").unwrap();
+        assert_relative_eq!(
+            callback(1.0, key).unwrap(),
+            expected::HARTREE_IN_ELECTRONVOLTS
+        );
+        assert_eq!(
+            lookup(Category::Power, "mw").map(|(key, _)| key),
+            Err(ConversionError::UnknownUnit("mw".to_string()))
+        );
+        assert_eq!(
+            lookup(Category::Length, "parsnip").map(|(key, _)| key),
+            Err(ConversionError::UnknownUnit("parsnip".to_string()))
+        );
+    }
+
+    #[test]
+    fn bits_and_bytes_are_not_confused() {
+        assert_eq!(lookup(Category::Data, " B ").unwrap().0, "B");
+        assert_eq!(lookup(Category::Data, "bit").unwrap().0, "bit");
+        for spelling in ["b", "gb", "Kib"] {
+            assert_eq!(
+                lookup(Category::Data, spelling).map(|(key, _)| key),
+                Err(ConversionError::UnknownUnit(spelling.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn missing_units_are_errors_instead_of_panics() {
+        let bohr_to_metres = crate::length::convert_bohr_to_metres();
+        assert_eq!(
+            get_converter(bohr_to_metres, "m").unwrap()(1.0, "m"),
+            Ok(crate::length::BOHR_RADIUS_TO_METRES)
+        );
+        assert_eq!(
+            get_converter(bohr_to_metres, "xyz").err(),
+            Some(ConversionError::UnknownUnit("xyz".to_string()))
+        );
+        assert_eq!(
+            Category::Energy.to_base_map().try_get("xyz").err(),
+            Some(ConversionError::UnknownUnit("xyz".to_string()))
+        );
+        assert!(Category::Energy.to_base_map().try_get("rcm").is_ok());
+    }
+}
@@ -0,0 +1,47 @@
+//! This module offers magnetic flux density unit conversion functions in double precision, i.e. f64.
+
+use crate::codata::ATOMIC_UNIT_OF_MAGNETIC_FLUX_DENSITY_TO_TESLA as ATOMIC_UNIT_TO_TESLA;
+use crate::table::conversion_table;
+
+const GAUSS_TO_TESLA: f64 = 1e-4;
+
+conversion_table!(
+    kind = "magnetic flux density",
+    category = MagneticField,
+    a_kind = "a magnetic flux density",
+    base = "tesla",
+    /// Returns the size of the magnetic flux density unit in tesla.
+    factor = teslas_per,
+    to_base = to_tesla,
+    from_base = from_tesla,
+    to_map = convert_2_tesla_from,
+    from_map = convert_from_tesla_2,
+    units = {
+        "T" => 1.0,
+        "G" => GAUSS_TO_TESLA,
+        "au" => ATOMIC_UNIT_TO_TESLA,
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::codata::expected;
+
+    #[test]
+    fn tesla_and_gauss() {
+        assert_relative_eq!(try_convert(1.0, "T", "G").unwrap(), 1e4);
+        let gauss = try_convert(0.35, "T", "G").unwrap();
+        assert_relative_eq!(try_convert(gauss, "G", "T").unwrap(), 0.35);
+    }
+
+    #[test]
+    fn atomic_unit_is_the_selected_revision() {
+        assert_relative_eq!(
+            try_convert(1.0, "au", "T").unwrap(),
+            expected::ATOMIC_UNIT_OF_MAGNETIC_FLUX_DENSITY_IN_TESLA
+        );
+    }
+}
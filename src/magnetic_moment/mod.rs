@@ -0,0 +1,53 @@
+//! This module offers magnetic moment unit conversion functions in double precision, i.e. f64.
+//! Besides the SI unit J/T it knows the Bohr magneton and the nuclear magneton used in EPR and
+//! NMR spectroscopy.
+
+use crate::codata::{BOHR_MAGNETON_TO_JOULES_PER_TESLA, NUCLEAR_MAGNETON_TO_JOULES_PER_TESLA};
+use crate::table::conversion_table;
+
+conversion_table!(
+    kind = "magnetic moment",
+    category = MagneticMoment,
+    a_kind = "a magnetic moment",
+    base = "joules per tesla",
+    /// Returns the size of the magnetic moment unit in joules per tesla.
+    factor = joules_per_tesla_per,
+    to_base = to_joules_per_tesla,
+    from_base = from_joules_per_tesla,
+    to_map = convert_2_jpt_from,
+    from_map = convert_from_jpt_2,
+    units = {
+        "J/T" => 1.0,
+        "muB" => BOHR_MAGNETON_TO_JOULES_PER_TESLA,
+        "muN" => NUCLEAR_MAGNETON_TO_JOULES_PER_TESLA,
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::codata::expected;
+
+    #[test]
+    fn magneton_ratio_is_proton_electron_mass_ratio() {
+        assert_relative_eq!(
+            try_convert(1.0, "muB", "muN").unwrap(),
+            1_836.152_673,
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn magnetons_are_the_selected_revision() {
+        assert_relative_eq!(
+            try_convert(2.0, "muB", "J/T").unwrap(),
+            2.0 * expected::BOHR_MAGNETON_IN_JOULES_PER_TESLA
+        );
+        assert_relative_eq!(
+            try_convert(1.0, "muN", "J/T").unwrap(),
+            expected::NUCLEAR_MAGNETON_IN_JOULES_PER_TESLA
+        );
+    }
+}
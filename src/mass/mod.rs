@@ -0,0 +1,55 @@
+//! This module offers mass unit conversion functions in double precision, i.e. f64.
+//! Conversion factors were taken from [here](https://physics.nist.gov/cuu/Constants/)
+
+use crate::codata;
+use crate::table::conversion_table;
+
+const GRAM_TO_KILOGRAMS: f64 = 1e-3;
+const ATOMIC_MASS_UNIT_TO_KILOGRAMS: f64 = codata::ATOMIC_MASS_UNIT_TO_KILOGRAMS;
+pub(crate) const ELECTRON_MASS_TO_KILOGRAMS: f64 = codata::ELECTRON_MASS_TO_KILOGRAMS;
+const POUND_TO_KILOGRAMS: f64 = 0.453_592_37;
+
+conversion_table!(
+    kind = "mass",
+    category = Mass,
+    a_kind = "a mass",
+    base = "kilograms",
+    /// Returns the size of the mass unit in kilograms. The Dalton is another name for the atomic mass
+    /// unit and shares its value.
+    factor = kilograms_per,
+    to_base = to_kilograms,
+    from_base = from_kilograms,
+    to_map = convert_2_kg_from,
+    from_map = convert_from_kg_2,
+    units = {
+        "kg" => 1.0,
+        "g" => GRAM_TO_KILOGRAMS,
+        "amu" => ATOMIC_MASS_UNIT_TO_KILOGRAMS,
+        "Da" => ATOMIC_MASS_UNIT_TO_KILOGRAMS,
+        "me" => ELECTRON_MASS_TO_KILOGRAMS,
+        "lb" => POUND_TO_KILOGRAMS,
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::codata::expected;
+
+    #[test]
+    fn amu_round_trip() {
+        let kg = convert_2_kg_from()["amu"](1.0, "amu").unwrap();
+        assert_eq!(kg, expected::ATOMIC_MASS_UNIT_IN_KILOGRAMS);
+        let amu = convert_from_kg_2()["amu"](kg, "amu").unwrap();
+        assert_relative_eq!(amu, 1.0, max_relative = 1e-12);
+        assert_eq!(try_convert(3.0, "Da", "amu"), Ok(3.0));
+        assert_relative_eq!(try_convert(1.0, "lb", "g").unwrap(), 453.592_37);
+        assert_relative_eq!(
+            try_convert(1.0, "amu", "me").unwrap(),
+            1822.888,
+            max_relative = 1e-6
+        );
+    }
+}
@@ -0,0 +1,74 @@
+//! This module offers a measurement type carrying a value together with its standard
+//! uncertainty, which conversions propagate to linear order.
+
+use crate::dispatch::Conversion;
+use crate::ConversionError;
+
+/// A value with its standard uncertainty, e.g. 1.000 ± 0.002 bohr.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Measurement {
+    pub value: f64,
+    /// Standard uncertainty, in the unit of the value.
+    pub uncertainty: f64,
+}
+
+impl Measurement {
+    pub fn new(value: f64, uncertainty: f64) -> Self {
+        Measurement { value, uncertainty }
+    }
+
+    /// Returns the uncertainty relative to the value.
+    pub fn relative_uncertainty(&self) -> f64 {
+        (self.uncertainty / self.value).abs()
+    }
+
+    /// Converts the measurement from the unit `from` to the unit `to` like [`crate::convert`].
+    ///
+    /// The uncertainty is multiplied by the absolute slope of the conversion at the value, i.e.
+    /// by the factor of proportional units and by |dE/dλ| for reciprocal units like `nm` to `eV`,
+    /// which keeps the relative uncertainty. The offset of affine units does not affect it, only
+    /// the size of the degree does, so converting degC to K leaves it unchanged.
+    pub fn convert(&self, from: &str, to: &str) -> Result<Measurement, ConversionError> {
+        let conversion = Conversion::resolve(from, to)?;
+        Ok(Measurement {
+            value: conversion.apply(self.value)?,
+            uncertainty: self.uncertainty * conversion.slope(self.value)?.abs(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::codata::expected;
+
+    #[test]
+    fn relative_uncertainty_is_preserved() {
+        let length = Measurement::new(1.0, 0.002);
+        let converted = length.convert("bohr", "ang").unwrap();
+        assert_relative_eq!(converted.value, expected::BOHR_RADIUS_IN_ANGSTROM);
+        assert_relative_eq!(converted.relative_uncertainty(), 0.002);
+
+        let photon = Measurement::new(500.0, 1.0).convert("nm", "eV").unwrap();
+        assert_relative_eq!(photon.value, 2.479_683_97, max_relative = 1e-8);
+        assert_relative_eq!(photon.relative_uncertainty(), 0.002, max_relative = 1e-12);
+
+        let celsius = Measurement::new(25.0, 0.1);
+        assert_relative_eq!(celsius.convert("degC", "K").unwrap().uncertainty, 0.1);
+        assert_relative_eq!(
+            celsius.convert("degC", "degF").unwrap().uncertainty,
+            0.18,
+            max_relative = 1e-12
+        );
+        assert_eq!(
+            celsius.convert("degC", "kg"),
+            Err(ConversionError::DimensionMismatch {
+                from_category: crate::Category::Temperature,
+                to_category: crate::Category::Mass,
+            })
+        );
+    }
+}
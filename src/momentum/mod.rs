@@ -0,0 +1,50 @@
+//! This module offers momentum unit conversion functions in double precision, i.e. f64.
+//! The base unit may be written `kg*m/s` or `kg·m/s`. The atomic unit of momentum ħ/a₀ is the
+//! electron mass times the atomic unit of velocity.
+
+use crate::atomic_units::ATOMIC_UNIT_OF_VELOCITY_TO_MPS;
+use crate::mass::ELECTRON_MASS_TO_KILOGRAMS;
+use crate::table::conversion_table;
+
+/// Atomic unit of momentum in kg·m/s, derived from the electron mass and the atomic unit of
+/// velocity.
+const ATOMIC_UNIT_TO_KGMPS: f64 = ELECTRON_MASS_TO_KILOGRAMS * ATOMIC_UNIT_OF_VELOCITY_TO_MPS;
+
+conversion_table!(
+    kind = "momentum",
+    category = Momentum,
+    a_kind = "a momentum",
+    base = "kilogram metres per second",
+    /// Returns the size of the momentum unit in kilogram metres per second.
+    factor = kgmps_per,
+    to_base = to_kgmps,
+    from_base = from_kgmps,
+    to_map = convert_2_kgmps_from,
+    from_map = convert_from_kgmps_2,
+    units = {
+        "kg*m/s" => 1.0,
+        "kg·m/s" => 1.0,
+        "au" => ATOMIC_UNIT_TO_KGMPS,
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::atomic_units::{to_si, Quantity};
+
+    #[test]
+    fn atomic_unit_is_electron_mass_times_velocity() {
+        assert_relative_eq!(
+            try_convert(1.0, "au", "kg*m/s").unwrap(),
+            to_si(1.0, Quantity::Mass) * to_si(1.0, Quantity::Velocity)
+        );
+        assert_relative_eq!(
+            try_convert(1.0, "au", "kg*m/s").unwrap(),
+            1.992_851_914e-24,
+            max_relative = 1e-9
+        );
+    }
+}
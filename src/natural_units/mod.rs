@@ -0,0 +1,108 @@
+//! This module offers conversions between natural units with ħ = c = 1 and SI in double
+//! precision, i.e. f64.
+//!
+//! In natural units every quantity is a power of an energy, given here in electronvolt. Masses
+//! and momenta are energies, lengths and times are reciprocal energies. Converting to SI inserts
+//! the factors of ħ and c, e.g. a mass m in eV is m/c² in kilograms and a length is ħc/E in metres.
+
+use crate::energy::{EV_JOULE_CONVERSION_FACTOR, REDUCED_PLANCK_CONSTANT_EV, SPEED_OF_LIGHT};
+
+/// The dimensions of a quantity given in natural units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dimension {
+    /// An energy E in eV, SI unit joule.
+    Energy,
+    /// A mass E/c² in eV, SI unit kilogram.
+    Mass,
+    /// A momentum E/c in eV, SI unit kilogram metre per second.
+    Momentum,
+    /// A length ħc/E in eV⁻¹, SI unit metre.
+    Length,
+    /// A time ħ/E in eV⁻¹, SI unit second.
+    Time,
+}
+
+impl Dimension {
+    /// Returns the SI value of one natural unit of the dimension, i.e. of 1 eV or 1 eV⁻¹.
+    pub fn si_factor(self) -> f64 {
+        match self {
+            Dimension::Energy => EV_JOULE_CONVERSION_FACTOR,
+            Dimension::Mass => EV_JOULE_CONVERSION_FACTOR / (SPEED_OF_LIGHT * SPEED_OF_LIGHT),
+            Dimension::Momentum => EV_JOULE_CONVERSION_FACTOR / SPEED_OF_LIGHT,
+            Dimension::Length => REDUCED_PLANCK_CONSTANT_EV * SPEED_OF_LIGHT,
+            Dimension::Time => REDUCED_PLANCK_CONSTANT_EV,
+        }
+    }
+
+    /// Returns whether the dimension is a reciprocal energy.
+    fn is_reciprocal(self) -> bool {
+        matches!(self, Dimension::Length | Dimension::Time)
+    }
+}
+
+/// Function to convert a value in natural units to SI.
+/// # Arguments
+///  * `value_ev` - energy in eV the quantity is expressed by, e.g. 1e9 for a mass of 1 GeV/c² or
+///    a length of 1 GeV⁻¹
+///  * `dimension` - the dimension of the quantity, determining the SI unit of the result
+///
+pub fn natural_to_si(value_ev: f64, dimension: Dimension) -> f64 {
+    if dimension.is_reciprocal() {
+        dimension.si_factor() / value_ev
+    } else {
+        value_ev * dimension.si_factor()
+    }
+}
+
+/// Function to convert a value in SI to natural units, i.e. to the energy in eV expressing it.
+/// # Arguments
+///  * `value` - value in the SI unit of the dimension
+///  * `dimension` - the dimension of the quantity
+///
+pub fn si_to_natural(value: f64, dimension: Dimension) -> f64 {
+    if dimension.is_reciprocal() {
+        dimension.si_factor() / value
+    } else {
+        value / dimension.si_factor()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn electronvolt_scale_quantities() {
+        assert_relative_eq!(
+            natural_to_si(1.0, Dimension::Mass),
+            1.782_661_92e-36,
+            max_relative = 1e-8
+        );
+        assert_relative_eq!(
+            natural_to_si(1.0, Dimension::Length),
+            1.973_269_804e-7,
+            max_relative = 1e-8
+        );
+        // The electron mass of about 511 keV.
+        assert_relative_eq!(
+            natural_to_si(510_998.95, Dimension::Mass),
+            9.109_383_7e-31,
+            max_relative = 1e-7
+        );
+        for dimension in [
+            Dimension::Energy,
+            Dimension::Mass,
+            Dimension::Momentum,
+            Dimension::Length,
+            Dimension::Time,
+        ] {
+            assert_relative_eq!(
+                si_to_natural(natural_to_si(2.5e6, dimension), dimension),
+                2.5e6,
+                max_relative = 1e-15
+            );
+        }
+    }
+}
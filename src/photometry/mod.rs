@@ -0,0 +1,93 @@
+//! This module offers photometric conversion functions in double precision, i.e. f64.
+//! Luminous flux in lumen is luminous intensity in candela times solid angle in steradian, and
+//! illuminance in lux is luminous flux per square metre. Radiant power converts to luminous flux
+//! at the peak of the photopic response, 555 nm (540 THz).
+//! Solid angles are taken in steradian; other units convert with the solid_angle module.
+
+use core::f64::consts::{PI, TAU};
+
+use num_traits::Float;
+
+/// Luminous efficacy of monochromatic 540 THz (≈ 555 nm) radiation in lm/W, exact by the
+/// definition of the candela.
+pub const LUMINOUS_EFFICACY_555NM: f64 = 683.0;
+/// Solid angle of the full sphere, 4π sr.
+pub const FULL_SPHERE_STERADIANS: f64 = 4.0 * PI;
+
+/// Function to convert the radiant power of 555 nm light to its luminous flux.
+/// # Arguments
+///  * `watts` - radiant power in W
+///
+pub fn watts_to_lumens(watts: f64) -> f64 {
+    watts * LUMINOUS_EFFICACY_555NM
+}
+
+/// Function to convert the luminous flux of 555 nm light to its radiant power.
+/// # Arguments
+///  * `lumens` - luminous flux in lm
+///
+pub fn lumens_to_watts(lumens: f64) -> f64 {
+    lumens / LUMINOUS_EFFICACY_555NM
+}
+
+/// Function to compute the luminous flux emitted into a solid angle at constant intensity.
+/// # Arguments
+///  * `candela` - luminous intensity in cd
+///  * `steradians` - solid angle in sr, [`FULL_SPHERE_STERADIANS`] for an isotropic source
+///
+pub fn candela_to_lumens(candela: f64, steradians: f64) -> f64 {
+    candela * steradians
+}
+
+/// Function to compute the luminous intensity of a flux spread evenly over a solid angle.
+/// # Arguments
+///  * `lumens` - luminous flux in lm
+///  * `steradians` - solid angle in sr
+///
+pub fn lumens_to_candela(lumens: f64, steradians: f64) -> f64 {
+    lumens / steradians
+}
+
+/// Function to compute the illuminance of a flux falling evenly onto an area.
+/// # Arguments
+///  * `lumens` - luminous flux in lm
+///  * `square_metres` - illuminated area in m²
+///
+pub fn lumens_to_lux(lumens: f64, square_metres: f64) -> f64 {
+    lumens / square_metres
+}
+
+/// Function to compute the luminous flux falling onto an area at constant illuminance.
+/// # Arguments
+///  * `lux` - illuminance in lx
+///  * `square_metres` - illuminated area in m²
+///
+pub fn lux_to_lumens(lux: f64, square_metres: f64) -> f64 {
+    lux * square_metres
+}
+
+/// Function to compute the solid angle of a cone, 2π(1 - cos θ).
+/// # Arguments
+///  * `half_angle` - half of the apex angle in rad; π gives the full sphere
+///
+pub fn cone_steradians(half_angle: f64) -> f64 {
+    TAU * (1.0 - Float::cos(half_angle))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn photopic_peak_and_solid_angles() {
+        assert_eq!(watts_to_lumens(1.0), 683.0);
+        assert_relative_eq!(lumens_to_watts(watts_to_lumens(0.25)), 0.25);
+        assert_relative_eq!(cone_steradians(PI), FULL_SPHERE_STERADIANS);
+        let lumens = candela_to_lumens(1.0, FULL_SPHERE_STERADIANS);
+        assert_relative_eq!(lumens, 12.566_370_614_359_172);
+        assert_relative_eq!(lumens_to_candela(lumens, FULL_SPHERE_STERADIANS), 1.0);
+        assert_relative_eq!(lumens_to_lux(lux_to_lumens(500.0, 2.0), 2.0), 500.0);
+    }
+}
@@ -0,0 +1,118 @@
+//! This module offers a photon helper moving between the spectroscopic descriptions of light:
+//! wavenumber, wavelength, energy and frequency.
+
+use crate::energy;
+
+/// The spectroscopic quantity a [`Photon`] was constructed from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Given {
+    Wavenumber,
+    Wavelength,
+    Energy,
+    Frequency,
+}
+
+impl Given {
+    /// Key of the unit of the quantity in the conversion maps of the energy module.
+    fn key(self) -> &'static str {
+        match self {
+            Given::Wavenumber => "rcm",
+            Given::Wavelength => "nm",
+            Given::Energy => "eV",
+            Given::Frequency => "THz",
+        }
+    }
+}
+
+/// A photon described by any one of wavenumber in cm⁻¹, wavelength in nm, energy in eV or
+/// frequency in THz. The value it was constructed from is kept, so reading it back is exact; the
+/// other descriptions are computed with the conversions of the energy module.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Photon {
+    value: f64,
+    given: Given,
+}
+
+impl Photon {
+    pub fn from_wavenumber(rcm: f64) -> Self {
+        Photon {
+            value: rcm,
+            given: Given::Wavenumber,
+        }
+    }
+
+    pub fn from_wavelength(nm: f64) -> Self {
+        Photon {
+            value: nm,
+            given: Given::Wavelength,
+        }
+    }
+
+    pub fn from_energy(ev: f64) -> Self {
+        Photon {
+            value: ev,
+            given: Given::Energy,
+        }
+    }
+
+    pub fn from_frequency(thz: f64) -> Self {
+        Photon {
+            value: thz,
+            given: Given::Frequency,
+        }
+    }
+
+    fn get(&self, wanted: Given) -> f64 {
+        if wanted == self.given {
+            return self.value;
+        }
+        energy::try_convert(self.value, self.given.key(), wanted.key())
+            .expect("photon units are registered in the energy conversion maps")
+    }
+
+    /// Returns the wavenumber in cm⁻¹.
+    pub fn wavenumber(&self) -> f64 {
+        self.get(Given::Wavenumber)
+    }
+
+    /// Returns the wavelength in nm.
+    pub fn wavelength(&self) -> f64 {
+        self.get(Given::Wavelength)
+    }
+
+    /// Returns the energy in eV.
+    pub fn energy(&self) -> f64 {
+        self.get(Given::Energy)
+    }
+
+    /// Returns the frequency in THz.
+    pub fn frequency(&self) -> f64 {
+        self.get(Given::Frequency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn descriptions_agree() {
+        let green = Photon::from_wavelength(500.0);
+        assert_eq!(green.wavelength(), 500.0);
+        assert_relative_eq!(green.wavenumber(), 20_000.0, max_relative = 1e-8);
+        assert_relative_eq!(green.frequency(), 599.584_916, max_relative = 1e-8);
+        let from_ev = Photon::from_energy(green.energy());
+        assert_relative_eq!(from_ev.wavelength(), 500.0, max_relative = 1e-8);
+        assert_relative_eq!(from_ev.frequency(), green.frequency(), max_relative = 1e-8);
+
+        let landmark = Photon::from_wavelength(1240.0);
+        assert_relative_eq!(landmark.energy(), 1.0, max_relative = 1e-3);
+        assert_relative_eq!(
+            Photon::from_energy(1.0).wavelength(),
+            1_239.841_984,
+            max_relative = 1e-8
+        );
+    }
+}
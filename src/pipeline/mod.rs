@@ -0,0 +1,144 @@
+//! This module offers a builder composing several conversions into one pipeline, e.g. for a
+//! data-cleaning step declared once and applied to every record.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::dispatch::common_category;
+use crate::{Category, ConversionError, Converter};
+
+/// Builder of a [`ConversionPipeline`], e.g.
+/// `Pipeline::new().then("bohr", "ang").then("ang", "nm").build()`.
+#[derive(Debug, Clone, Default)]
+pub struct Pipeline {
+    stages: Vec<(String, String)>,
+}
+
+impl Pipeline {
+    /// Creates a pipeline without stages.
+    pub fn new() -> Self {
+        Pipeline::default()
+    }
+
+    /// Appends the conversion from `from` to `to` as the last stage.
+    pub fn then(mut self, from: &str, to: &str) -> Self {
+        self.stages.push((from.to_string(), to.to_string()));
+        self
+    }
+
+    /// Resolves the conversions of all stages.
+    ///
+    /// Every stage has to start from the unit the previous stage ended in. Returns
+    /// `DimensionMismatch` if it starts from a unit of another category, e.g. an energy stage
+    /// following a length stage, and `NoConversionPath` from the previous unit if it starts from
+    /// another unit of the same category, e.g. `nm` after `ang`. Returns the error of
+    /// [`Converter::new`] if a stage can not be converted. A pipeline without stages returns the
+    /// values unchanged.
+    pub fn build(self) -> Result<ConversionPipeline, ConversionError> {
+        let mut converters = Vec::new();
+        let mut last: Option<(String, Category)> = None;
+        for (from, to) in self.stages {
+            let converter = Converter::new(&from, &to)?;
+            let category = common_category(&from, &to)?;
+            if let Some((last_unit, last_category)) = last.filter(|(unit, _)| *unit != from) {
+                return Err(if last_category == category {
+                    ConversionError::NoConversionPath {
+                        from: last_unit,
+                        to: from,
+                    }
+                } else {
+                    ConversionError::DimensionMismatch {
+                        from_category: last_category,
+                        to_category: category,
+                    }
+                });
+            }
+            converters.push(converter);
+            last = Some((to, category));
+        }
+        Ok(ConversionPipeline { converters })
+    }
+}
+
+/// A chain of conversions built by [`Pipeline::build`], applied one after the other.
+#[derive(Clone)]
+pub struct ConversionPipeline {
+    converters: Vec<Converter>,
+}
+
+impl ConversionPipeline {
+    /// Converts `value` through all stages.
+    pub fn apply(&self, value: f64) -> f64 {
+        self.converters
+            .iter()
+            .fold(value, |value, converter| converter.apply(value))
+    }
+
+    /// Converts every value of `values` in place through all stages.
+    pub fn apply_slice(&self, values: &mut [f64]) {
+        for converter in &self.converters {
+            converter.apply_slice(values);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::convert;
+    use crate::length::BOHR_RADIUS_TO_ANGSTROM;
+
+    #[test]
+    fn chains_length_stages() {
+        let pipeline = Pipeline::new()
+            .then("bohr", "ang")
+            .then("ang", "nm")
+            .build()
+            .unwrap();
+        assert_relative_eq!(pipeline.apply(10.0), BOHR_RADIUS_TO_ANGSTROM);
+        let mut values = vec![1.0, 2.0];
+        pipeline.apply_slice(&mut values);
+        assert_relative_eq!(values[0], convert(1.0, "bohr", "nm").unwrap());
+        assert_relative_eq!(values[1], convert(2.0, "bohr", "nm").unwrap());
+
+        let photon = Pipeline::new()
+            .then("bohr", "nm")
+            .then("nm", "eV")
+            .build()
+            .unwrap();
+        assert_relative_eq!(
+            photon.apply(1.0),
+            convert(convert(1.0, "bohr", "nm").unwrap(), "nm", "eV").unwrap()
+        );
+        assert_eq!(Pipeline::new().build().unwrap().apply(2.5), 2.5);
+    }
+
+    #[test]
+    fn mismatched_stages_fail_to_build() {
+        assert_eq!(
+            Pipeline::new()
+                .then("bohr", "nm")
+                .then("eV", "Ry")
+                .build()
+                .err(),
+            Some(ConversionError::DimensionMismatch {
+                from_category: Category::Length,
+                to_category: Category::Energy,
+            })
+        );
+        assert_eq!(
+            Pipeline::new()
+                .then("bohr", "ang")
+                .then("nm", "m")
+                .build()
+                .err(),
+            Some(ConversionError::NoConversionPath {
+                from: "ang".to_string(),
+                to: "nm".to_string(),
+            })
+        );
+    }
+}
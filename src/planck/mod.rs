@@ -0,0 +1,78 @@
+//! This module offers conversions between natural Planck units and SI in double precision, i.e.
+//! f64. Values in SI are in the base units of the length, time, mass and energy modules, i.e.
+//! metres, seconds, kilograms and joule.
+
+/// Planck length √(ħG/c³) in metres.
+const PLANCK_LENGTH_TO_METRES: f64 = 1.616_255e-35;
+/// Planck time √(ħG/c⁵) in seconds.
+const PLANCK_TIME_TO_SECONDS: f64 = 5.391_247e-44;
+/// Planck mass √(ħc/G) in kilograms.
+const PLANCK_MASS_TO_KILOGRAMS: f64 = 2.176_434e-8;
+/// Planck energy √(ħc⁵/G) in joule.
+const PLANCK_ENERGY_TO_JOULE: f64 = 1.956_081e9;
+
+/// The physical quantities having a Planck unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Quantity {
+    /// Planck length, SI unit metre.
+    Length,
+    /// Planck time, SI unit second.
+    Time,
+    /// Planck mass, SI unit kilogram.
+    Mass,
+    /// Planck energy, SI unit joule.
+    Energy,
+}
+
+impl Quantity {
+    /// Returns the size of the Planck unit of the quantity in its SI unit.
+    pub fn si_factor(self) -> f64 {
+        match self {
+            Quantity::Length => PLANCK_LENGTH_TO_METRES,
+            Quantity::Time => PLANCK_TIME_TO_SECONDS,
+            Quantity::Mass => PLANCK_MASS_TO_KILOGRAMS,
+            Quantity::Energy => PLANCK_ENERGY_TO_JOULE,
+        }
+    }
+}
+
+/// Function to convert a value in Planck units to SI.
+/// # Arguments
+///  * `value` - value in Planck units
+///  * `quantity` - the quantity the value describes, determining the SI unit of the result
+///
+pub fn to_si(value: f64, quantity: Quantity) -> f64 {
+    value * quantity.si_factor()
+}
+
+/// Function to convert a value in SI to Planck units.
+/// # Arguments
+///  * `value` - value in the SI unit of the quantity
+///  * `quantity` - the quantity the value describes
+///
+pub fn from_si(value: f64, quantity: Quantity) -> f64 {
+    value / quantity.si_factor()
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::energy::SPEED_OF_LIGHT;
+
+    #[test]
+    fn planck_units_are_consistent_with_c() {
+        assert_relative_eq!(
+            to_si(1.0, Quantity::Energy) / to_si(1.0, Quantity::Mass),
+            SPEED_OF_LIGHT * SPEED_OF_LIGHT,
+            max_relative = 1e-6
+        );
+        assert_relative_eq!(
+            to_si(1.0, Quantity::Length) / to_si(1.0, Quantity::Time),
+            SPEED_OF_LIGHT,
+            max_relative = 1e-6
+        );
+        assert_relative_eq!(from_si(to_si(2.5, Quantity::Time), Quantity::Time), 2.5);
+    }
+}
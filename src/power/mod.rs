@@ -0,0 +1,47 @@
+//! This module offers power unit conversion functions in double precision, i.e. f64.
+//! Mechanical (`hp`) and metric (`PS`) horsepower are deliberately kept apart.
+
+use crate::table::conversion_table;
+
+const KILO: f64 = 1e3;
+const MEGA: f64 = 1e6;
+const HORSEPOWER_TO_WATT: f64 = 745.699_871_582;
+const METRIC_HORSEPOWER_TO_WATT: f64 = 735.498_75;
+const CALORIE_PER_SECOND_TO_WATT: f64 = 4.184;
+
+conversion_table!(
+    kind = "power",
+    category = Power,
+    a_kind = "a power",
+    base = "watt",
+    /// Returns the size of the (prefixed) power unit in watt.
+    factor = watts_per,
+    to_base = to_watt,
+    from_base = from_watt,
+    to_map = convert_2_watt_from,
+    from_map = convert_from_watt_2,
+    units = {
+        "W" => 1.0,
+        "kW" => KILO,
+        "MW" => MEGA,
+        "hp" => HORSEPOWER_TO_WATT,
+        "PS" => METRIC_HORSEPOWER_TO_WATT,
+        "cal/s" => CALORIE_PER_SECOND_TO_WATT,
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn horsepower_variants_and_prefixes() {
+        assert_relative_eq!(try_convert(1.0, "hp", "W").unwrap(), 745.699_871_582);
+        assert_relative_eq!(try_convert(1.0, "PS", "W").unwrap(), 735.498_75);
+        assert!(try_convert(1.0, "hp", "PS").unwrap() > 1.0);
+        assert_relative_eq!(try_convert(1.0, "MW", "kW").unwrap(), 1_000.0);
+        assert_relative_eq!(try_convert(4.184, "W", "cal/s").unwrap(), 1.0);
+    }
+}
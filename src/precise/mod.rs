@@ -0,0 +1,211 @@
+//! This module offers length and energy conversions evaluated in the precision of the float type,
+//! for float types more precise than f64.
+//!
+//! The functions of the length and energy modules cast their f64 constants to the float type, so
+//! even on a higher-precision type the result is only as exact as the nearest f64 to each
+//! factor. Here the factors are kept in their decimal form and parsed in the float type instead.
+//! With the `high-precision` feature the double-double `TwoFloat` with about 106 bits of
+//! mantissa is available as such a type; the functions are generic over every `Float`, for f64
+//! they agree with the length and energy modules up to rounding.
+
+use num_traits::Float;
+
+use crate::codata;
+use crate::float::cast;
+
+#[cfg(feature = "high-precision")]
+pub use twofloat::TwoFloat;
+
+/// Decimal forms of the conversion factors of the length and energy modules.
+const BOHR_RADIUS_TO_METRES: &str = codata::BOHR_RADIUS_TO_METRES_DECIMAL;
+const ANGSTROEM: &str = "1e-10";
+const HARTREE_EV_CONVERSION_FACTOR: &str = codata::HARTREE_EV_CONVERSION_FACTOR_DECIMAL;
+const EV_REC_CENTIMETRES_CONVERSION_FACTOR: &str = "8065.543937";
+const HARTREE_REC_CENTIMETRES_CONVERSION_FACTOR: &str =
+    codata::HARTREE_REC_CENTIMETRES_CONVERSION_FACTOR_DECIMAL;
+const EV_JOULE_CONVERSION_FACTOR: &str = "1.602176634e-19";
+
+/// Divides `a` by `b` with one correction step, q + (a - q·b)/b. The residual is computed in the
+/// precision of `T`, so the quotient is as exact as the multiplication of `T` even where its
+/// division is not, as for `TwoFloat`, whose quotients can be only as exact as f64.
+fn divide<T: Float>(a: T, b: T) -> T {
+    let q = a / b;
+    q + (a - q * b) / b
+}
+
+/// Evaluates a decimal number like `5.29177210903e-11` in the float type `T`.
+///
+/// The digits are accumulated as an integer and then scaled by the power of ten, so the only
+/// rounding is that of `T`'s arithmetic.
+///
+/// # Panics
+/// Panics if `decimal` is not a plain decimal number with optional exponent, which only affects
+/// the constants of this module.
+fn parse_decimal<T: Float>(decimal: &str) -> T {
+    let (mantissa, exponent) = match decimal.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (
+            mantissa,
+            exponent.parse::<i32>().expect("exponent is an integer"),
+        ),
+        None => (decimal, 0),
+    };
+    let (negative, mantissa) = match mantissa.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, mantissa),
+    };
+    let ten: T = cast(10.0);
+    let mut digits = T::zero();
+    let mut fraction_digits = 0;
+    let mut in_fraction = false;
+    for c in mantissa.chars() {
+        if c == '.' {
+            in_fraction = true;
+            continue;
+        }
+        let digit = c.to_digit(10).expect("mantissa consists of digits");
+        digits = digits * ten + cast(f64::from(digit));
+        if in_fraction {
+            fraction_digits += 1;
+        }
+    }
+    let scale = exponent - fraction_digits;
+    let value = if scale < 0 {
+        divide(digits, ten.powi(-scale))
+    } else {
+        digits * ten.powi(scale)
+    };
+    if negative {
+        -value
+    } else {
+        value
+    }
+}
+
+/// Converts bohr to metres.
+pub fn bohr_to_metres<T: Float>(b: T) -> T {
+    b * parse_decimal(BOHR_RADIUS_TO_METRES)
+}
+
+/// Converts metres to bohr.
+pub fn metres_to_bohr<T: Float>(v: T) -> T {
+    divide(v, parse_decimal(BOHR_RADIUS_TO_METRES))
+}
+
+/// Converts bohr to angstroem.
+pub fn bohr_to_ang<T: Float>(b: T) -> T {
+    divide(bohr_to_metres(b), parse_decimal(ANGSTROEM))
+}
+
+/// Converts angstroem to bohr.
+pub fn ang_to_bohr<T: Float>(v: T) -> T {
+    metres_to_bohr(v * parse_decimal(ANGSTROEM))
+}
+
+/// Converts an energy in Hartree to electronvolt.
+pub fn hartree_2_ev<T: Float>(energy_in_hartree: T) -> T {
+    energy_in_hartree * parse_decimal(HARTREE_EV_CONVERSION_FACTOR)
+}
+
+/// Converts an energy in electronvolt to Hartree.
+pub fn ev_2_hartree<T: Float>(energy_in_ev: T) -> T {
+    divide(energy_in_ev, parse_decimal(HARTREE_EV_CONVERSION_FACTOR))
+}
+
+/// Converts an energy in electronvolt to wavenumbers in cm⁻¹.
+pub fn ev_2_rcm<T: Float>(energy_in_ev: T) -> T {
+    energy_in_ev * parse_decimal(EV_REC_CENTIMETRES_CONVERSION_FACTOR)
+}
+
+/// Converts a wavenumber in cm⁻¹ to an energy in electronvolt.
+pub fn rcm_2_ev<T: Float>(energy_in_rcm: T) -> T {
+    divide(
+        energy_in_rcm,
+        parse_decimal(EV_REC_CENTIMETRES_CONVERSION_FACTOR),
+    )
+}
+
+/// Converts an energy in Hartree to wavenumbers in cm⁻¹.
+pub fn hartree_2_rcm<T: Float>(energy_in_hartree: T) -> T {
+    energy_in_hartree * parse_decimal(HARTREE_REC_CENTIMETRES_CONVERSION_FACTOR)
+}
+
+/// Converts a wavenumber in cm⁻¹ to an energy in Hartree.
+pub fn rcm_2_hartree<T: Float>(energy_in_rcm: T) -> T {
+    divide(
+        energy_in_rcm,
+        parse_decimal(HARTREE_REC_CENTIMETRES_CONVERSION_FACTOR),
+    )
+}
+
+/// Converts an energy in joule to electronvolt.
+pub fn j_2_ev<T: Float>(energy_in_j: T) -> T {
+    divide(energy_in_j, parse_decimal(EV_JOULE_CONVERSION_FACTOR))
+}
+
+/// Converts an energy in electronvolt to joule.
+pub fn ev_2_j<T: Float>(energy_in_ev: T) -> T {
+    energy_in_ev * parse_decimal(EV_JOULE_CONVERSION_FACTOR)
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::{energy, length};
+
+    #[test]
+    fn decimal_forms_match_the_f64_constants() {
+        for (decimal, constant) in [
+            (BOHR_RADIUS_TO_METRES, length::BOHR_RADIUS_TO_METRES),
+            (ANGSTROEM, length::ANGSTROEM),
+            (
+                HARTREE_EV_CONVERSION_FACTOR,
+                energy::HARTREE_EV_CONVERSION_FACTOR,
+            ),
+            (
+                EV_REC_CENTIMETRES_CONVERSION_FACTOR,
+                energy::EV_REC_CENTIMETRES_CONVERSION_FACTOR,
+            ),
+            (
+                HARTREE_REC_CENTIMETRES_CONVERSION_FACTOR,
+                energy::HARTREE_REC_CENTIMETRES_CONVERSION_FACTOR,
+            ),
+            (
+                EV_JOULE_CONVERSION_FACTOR,
+                energy::EV_JOULE_CONVERSION_FACTOR,
+            ),
+        ] {
+            assert_relative_eq!(
+                parse_decimal::<f64>(decimal),
+                constant,
+                max_relative = f64::EPSILON
+            );
+        }
+        assert_eq!(parse_decimal::<f64>("-2.5e3"), -2_500.0);
+        assert_relative_eq!(bohr_to_ang(1.0_f64), length::bohr_to_ang_const(1.0));
+    }
+
+    #[cfg(feature = "high-precision")]
+    #[test]
+    fn two_float_round_trips_beat_f64() {
+        let bohr_in_ang: TwoFloat =
+            parse_decimal(crate::codata::expected::BOHR_RADIUS_IN_ANGSTROM_DECIMAL);
+        let error = (bohr_to_ang(TwoFloat::from(1.0)) - bohr_in_ang).abs() / bohr_in_ang;
+        assert!(error < TwoFloat::from(1e-30), "{}", error);
+
+        let mut f64_error = 0.0;
+        let mut two_error = TwoFloat::from(0.0);
+        for x in [1e-3, 0.1, 1.0, 42.0, 6_000.0, 1.234_567_89e5] {
+            let two = TwoFloat::from(x);
+            f64_error += ((rcm_2_ev(ev_2_rcm(x)) - x) / x).abs();
+            two_error += ((rcm_2_ev(ev_2_rcm(two)) - two) / two).abs();
+            f64_error += ((ang_to_bohr(bohr_to_ang(x)) - x) / x).abs();
+            two_error += ((ang_to_bohr(bohr_to_ang(two)) - two) / two).abs();
+            f64_error += ((ev_2_hartree(hartree_2_ev(x)) - x) / x).abs();
+            two_error += ((ev_2_hartree(hartree_2_ev(two)) - two) / two).abs();
+        }
+        assert!(f64_error > 1e-17, "{}", f64_error);
+        assert!(two_error < TwoFloat::from(1e-30), "{}", two_error);
+    }
+}
@@ -0,0 +1,114 @@
+//! This module offers the normalization of unit prefixes shared by the conversion modules.
+
+use alloc::borrow::Cow;
+use alloc::format;
+
+/// Brings the different spellings of the micro prefix into one form, the micro sign `µ`
+/// (U+00B5). The Greek small letter mu `μ` (U+03BC) and a plain `u` in front of a unit are
+/// rewritten to it, and the spelled out micrometres `mu` and `micron` become `µm`. Every other
+/// unit is returned unchanged.
+pub(crate) fn normalize_micro(unit: &str) -> Cow<'_, str> {
+    match unit {
+        "mu" | "micron" => return Cow::Borrowed("µm"),
+        _ => {}
+    }
+    let mut chars = unit.chars();
+    match chars.next() {
+        Some('μ' | 'u') if !chars.as_str().is_empty() => {
+            Cow::Owned(format!("µ{}", chars.as_str()))
+        }
+        _ => Cow::Borrowed(unit),
+    }
+}
+
+/// Returns the factor of the SI prefix, e.g. `1e3` for `k`, or `None` for an unknown prefix. The
+/// empty prefix has the factor one and micro may be written `µ`, `μ` or `u`.
+pub(crate) fn si_prefix_factor(prefix: &str) -> Option<f64> {
+    match prefix {
+        "y" => Some(1e-24),
+        "z" => Some(1e-21),
+        "a" => Some(1e-18),
+        "f" => Some(1e-15),
+        "p" => Some(1e-12),
+        "n" => Some(1e-9),
+        "µ" | "μ" | "u" => Some(1e-6),
+        "m" => Some(1e-3),
+        "c" => Some(1e-2),
+        "d" => Some(1e-1),
+        "" => Some(1.0),
+        "da" => Some(1e1),
+        "h" => Some(1e2),
+        "k" => Some(1e3),
+        "M" => Some(1e6),
+        "G" => Some(1e9),
+        "T" => Some(1e12),
+        "P" => Some(1e15),
+        "E" => Some(1e18),
+        "Z" => Some(1e21),
+        "Y" => Some(1e24),
+        _ => None,
+    }
+}
+
+/// Splits `unit` into an SI prefix and the unit symbol `base` and returns the factor of the
+/// prefix, e.g. `1e-15` for `fm` with base `m`. Returns `None` if the unit does not end in
+/// `base` or the remainder is no SI prefix.
+pub(crate) fn prefixed_factor(unit: &str, base: &str) -> Option<f64> {
+    si_prefix_factor(unit.strip_suffix(base)?)
+}
+
+/// Splits `unit` into the factor of an SI prefix and a unit for which `is_unit` holds, e.g. `km`
+/// into `1e3` and `m`, or returns `None` if it can not be split so. Single letter prefixes are
+/// tried before `da`.
+pub(crate) fn split_si_prefix(unit: &str, is_unit: impl Fn(&str) -> bool) -> Option<(f64, &str)> {
+    unit.char_indices()
+        .skip(1)
+        .take(2)
+        .map(|(i, _)| unit.split_at(i))
+        .find_map(|(prefix, rest)| {
+            let factor = si_prefix_factor(prefix).filter(|_| is_unit(rest))?;
+            Some((factor, rest))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn micro_spellings() {
+        assert_eq!(normalize_micro("µm"), "µm");
+        assert_eq!(normalize_micro("μm"), "µm");
+        assert_eq!(normalize_micro("um"), "µm");
+        assert_eq!(normalize_micro("mu"), "µm");
+        assert_eq!(normalize_micro("micron"), "µm");
+        assert_eq!(normalize_micro("μs"), "µs");
+        assert_eq!(normalize_micro("uJ"), "µJ");
+        assert_eq!(normalize_micro("u"), "u");
+        assert_eq!(normalize_micro("mm"), "mm");
+    }
+
+    #[test]
+    fn si_prefixes() {
+        assert_eq!(si_prefix_factor("da"), Some(10.0));
+        assert_eq!(si_prefix_factor("M"), Some(1e6));
+        assert_eq!(si_prefix_factor("q"), None);
+        assert_eq!(prefixed_factor("Gm", "m"), Some(1e9));
+        assert_eq!(prefixed_factor("fm", "m"), Some(1e-15));
+        assert_eq!(prefixed_factor("m", "m"), Some(1.0));
+        assert_eq!(prefixed_factor("mm", "m"), Some(1e-3));
+        assert_eq!(prefixed_factor("ms", "m"), None);
+        assert_eq!(prefixed_factor("xm", "m"), None);
+    }
+
+    #[test]
+    fn splits_prefixed_units() {
+        let is_unit = |unit: &str| ["m", "eV"].contains(&unit);
+        assert_eq!(split_si_prefix("km", is_unit), Some((1e3, "m")));
+        assert_eq!(split_si_prefix("µm", is_unit), Some((1e-6, "m")));
+        assert_eq!(split_si_prefix("keV", is_unit), Some((1e3, "eV")));
+        assert_eq!(split_si_prefix("dam", is_unit), Some((10.0, "m")));
+        assert_eq!(split_si_prefix("m", is_unit), None);
+        assert_eq!(split_si_prefix("xm", is_unit), None);
+    }
+}
@@ -0,0 +1,146 @@
+//! This module offers pressure unit conversion functions in double precision, i.e. f64.
+//! As J/m³ equals Pa, it also converts between pressures and energy densities.
+
+use crate::affine::AffineConversion;
+use crate::table::conversion_table;
+use crate::{energy, length, ConversionError};
+
+const KILO: f64 = 1e3;
+const MEGA: f64 = 1e6;
+const GIGA: f64 = 1e9;
+const BAR_TO_PASCAL: f64 = 1e5;
+const ATMOSPHERE_TO_PASCAL: f64 = 101_325.0;
+const TORR_TO_PASCAL: f64 = ATMOSPHERE_TO_PASCAL / 760.0;
+const PSI_TO_PASCAL: f64 = 6_894.757;
+
+conversion_table!(
+    kind = "pressure",
+    category = Pressure,
+    a_kind = "a pressure",
+    base = "pascal",
+    /// Returns the size of the pressure unit in pascal. Millimetres of mercury and torr are
+    /// treated as the same unit, i.e. 1/760 of a standard atmosphere.
+    factor = pascals_per,
+    to_base = to_pascal,
+    from_base = from_pascal,
+    to_map = convert_2_pa_from,
+    from_map = convert_from_pa_2,
+    units = {
+        "Pa" => 1.0,
+        "kPa" => KILO,
+        "MPa" => MEGA,
+        "GPa" => GIGA,
+        "bar" => BAR_TO_PASCAL,
+        "atm" => ATMOSPHERE_TO_PASCAL,
+        "mmHg" => TORR_TO_PASCAL,
+        "torr" => TORR_TO_PASCAL,
+        "psi" => PSI_TO_PASCAL,
+    },
+    // Gauge pressures are measured against the standard atmosphere.
+    affine = {
+        "barg" => AffineConversion::new(BAR_TO_PASCAL, ATMOSPHERE_TO_PASCAL),
+        "psig" => AffineConversion::new(PSI_TO_PASCAL, ATMOSPHERE_TO_PASCAL),
+    }
+);
+
+/// Returns the volume of the cube of the length unit in m³.
+fn cubic_metres_per(length_unit: &str) -> Result<f64, ConversionError> {
+    let metres = length::try_convert(1.0, length_unit, "m")?;
+    Ok(metres * metres * metres)
+}
+
+/// Function to convert an energy density to a pressure.
+/// # Arguments
+///  * `value` - energy density in `energy_unit` per cubed `length_unit`, e.g. eV/Å³
+///  * `energy_unit` - unit of the energy module, e.g. `eV`
+///  * `length_unit` - unit of the length module whose cube is the volume, e.g. `ang`
+///  * `pressure_unit` - unit of the result, e.g. `GPa`
+///
+pub fn energy_density_to_pressure(
+    value: f64,
+    energy_unit: &str,
+    length_unit: &str,
+    pressure_unit: &str,
+) -> Result<f64, ConversionError> {
+    let joules = energy::try_convert(value, energy_unit, "J")?;
+    try_convert(joules / cubic_metres_per(length_unit)?, "Pa", pressure_unit)
+}
+
+/// Function to convert a pressure to an energy density.
+/// # Arguments
+///  * `value` - pressure in `pressure_unit`
+///  * `pressure_unit` - unit of the value, e.g. `GPa`
+///  * `energy_unit` - energy unit of the result, e.g. `eV`
+///  * `length_unit` - length unit whose cube is the volume of the result, e.g. `ang`
+///
+pub fn pressure_to_energy_density(
+    value: f64,
+    pressure_unit: &str,
+    energy_unit: &str,
+    length_unit: &str,
+) -> Result<f64, ConversionError> {
+    let joules_per_cubic_metre = try_convert(value, pressure_unit, "Pa")?;
+    energy::try_convert(
+        joules_per_cubic_metre * cubic_metres_per(length_unit)?,
+        "J",
+        energy_unit,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn torr_and_atmosphere() {
+        assert_eq!(try_convert(760.0, "torr", "atm"), Ok(1.0));
+        assert_eq!(try_convert(1.0, "atm", "Pa"), Ok(101_325.0));
+        assert_eq!(
+            try_convert(12.5, "mmHg", "Pa"),
+            try_convert(12.5, "torr", "Pa")
+        );
+        assert_relative_eq!(
+            try_convert(1.0, "bar", "psi").unwrap(),
+            14.503_774,
+            epsilon = 1e-5
+        );
+    }
+
+    #[test]
+    fn gauge_pressures_are_offset_by_an_atmosphere() {
+        assert_eq!(try_convert(0.0, "barg", "Pa"), Ok(101_325.0));
+        assert_relative_eq!(try_convert(1.0, "barg", "bar").unwrap(), 2.013_25);
+        assert_relative_eq!(try_convert(1.0, "atm", "psig").unwrap(), 0.0);
+        assert_relative_eq!(
+            try_convert(14.5, "psig", "barg").unwrap(),
+            0.999_740,
+            epsilon = 1e-6
+        );
+        assert_eq!(
+            affine_units()["psig"],
+            AffineConversion::new(PSI_TO_PASCAL, ATMOSPHERE_TO_PASCAL)
+        );
+    }
+
+    #[test]
+    fn energy_density_equivalence() {
+        assert_relative_eq!(
+            energy_density_to_pressure(1.0, "eV", "ang", "GPa").unwrap(),
+            160.217_663_4,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(
+            pressure_to_energy_density(160.217_663_4, "GPa", "eV", "ang").unwrap(),
+            1.0,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(
+            energy_density_to_pressure(1.0, "J", "m", "Pa").unwrap(),
+            1.0,
+            max_relative = 1e-12
+        );
+        assert!(energy_density_to_pressure(1.0, "eV", "eV", "GPa").is_err());
+    }
+}
@@ -0,0 +1,177 @@
+//! This module offers a quantity type carrying a value together with its unit.
+
+use alloc::string::{String, ToString};
+use core::str::FromStr;
+
+use crate::{convert, ConversionError};
+
+/// A value together with the unit it is given in.
+///
+/// With the `serde` feature it (de)serializes as `{ "value": 1.5, "unit": "nm" }`; deserializing
+/// fails for units not registered in any category.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "UncheckedQuantity")
+)]
+pub struct Quantity {
+    pub value: f64,
+    pub unit: String,
+}
+
+/// A deserialized quantity whose unit has not been checked yet.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct UncheckedQuantity {
+    value: f64,
+    unit: String,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedQuantity> for Quantity {
+    type Error = ConversionError;
+
+    fn try_from(q: UncheckedQuantity) -> Result<Self, Self::Error> {
+        if crate::Category::of(&q.unit).is_none() {
+            return Err(ConversionError::UnknownUnit(q.unit));
+        }
+        Ok(Quantity {
+            value: q.value,
+            unit: q.unit,
+        })
+    }
+}
+
+impl Quantity {
+    pub fn new(value: f64, unit: &str) -> Self {
+        Quantity {
+            value,
+            unit: unit.to_string(),
+        }
+    }
+
+    /// Converts the quantity into `target_unit` using [`convert`]. The result is a new quantity
+    /// so that conversions can be chained.
+    pub fn convert_to(&self, target_unit: &str) -> Result<Quantity, ConversionError> {
+        let value = convert(self.value, &self.unit, target_unit)?;
+        Ok(Quantity::new(value, target_unit))
+    }
+}
+
+impl FromStr for Quantity {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value, unit) = parse_quantity(s)?;
+        Ok(Quantity { value, unit })
+    }
+}
+
+/// Parses strings like `"1.5 nm"`, `"6.022e23 kJ/mol"` or `"-40degC"` into the value and the
+/// unit token. The space between number and unit is optional; without one the longest leading
+/// part that parses as a number is taken, so `"1eV"` is one electron volt.
+/// Returns `InvalidQuantity` if there is no number or no unit, or if the number ends in an
+/// exponent without digits like `"5e"` or `"1.5e-"`; elementary charges need the space, `"5 e"`.
+pub fn parse_quantity(s: &str) -> Result<(f64, String), ConversionError> {
+    let invalid = || ConversionError::InvalidQuantity(s.to_string());
+    let trimmed = s.trim();
+    let (value, unit) = match trimmed.split_once(char::is_whitespace) {
+        Some((number, unit)) => (number.parse::<f64>().map_err(|_| invalid())?, unit.trim()),
+        None if trimmed.parse::<f64>().is_ok() => return Err(invalid()),
+        None => {
+            let (value, unit) = trimmed
+                .char_indices()
+                .rev()
+                .filter(|&(i, c)| i > 0 && !c.is_ascii_digit() && c != '.')
+                .find_map(|(i, _)| {
+                    trimmed[..i]
+                        .parse::<f64>()
+                        .ok()
+                        .map(|value| (value, &trimmed[i..]))
+                })
+                .ok_or_else(invalid)?;
+            if matches!(unit, "e" | "E" | "e+" | "E+" | "e-" | "E-") {
+                return Err(invalid());
+            }
+            (value, unit)
+        }
+    };
+    if unit.is_empty() {
+        return Err(invalid());
+    }
+    Ok((value, unit.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::codata::expected;
+
+    #[test]
+    fn convert_and_chain() {
+        let ang = Quantity::new(1.0, "bohr").convert_to("ang").unwrap();
+        assert_eq!(ang.unit, "ang");
+        assert_relative_eq!(ang.value, expected::BOHR_RADIUS_IN_ANGSTROM);
+        let nm = ang.convert_to("nm").unwrap();
+        assert_relative_eq!(nm.value, expected::BOHR_RADIUS_IN_NANOMETRES);
+        let rcm = Quantity::new(2.0, "eV").convert_to("rcm").unwrap();
+        assert_relative_eq!(rcm.value, 16_131.087_874);
+        assert_eq!(
+            Quantity::new(1.0, "parsnip").convert_to("ang"),
+            Err(ConversionError::UnknownUnit("parsnip".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_value_and_unit() {
+        assert_eq!(parse_quantity("1.5 nm"), Ok((1.5, "nm".to_string())));
+        assert_eq!(
+            parse_quantity("  -40 degC  "),
+            Ok((-40.0, "degC".to_string()))
+        );
+        assert_eq!(
+            parse_quantity("6.022e23 kJ/mol"),
+            Ok((6.022e23, "kJ/mol".to_string()))
+        );
+        assert_eq!(parse_quantity("1.5nm"), Ok((1.5, "nm".to_string())));
+        assert_eq!(parse_quantity("1eV"), Ok((1.0, "eV".to_string())));
+        assert_eq!(parse_quantity("2e-3eV"), Ok((2e-3, "eV".to_string())));
+        assert_eq!(
+            parse_quantity("5e"),
+            Err(ConversionError::InvalidQuantity("5e".to_string()))
+        );
+        assert_eq!(
+            parse_quantity("1.5e-"),
+            Err(ConversionError::InvalidQuantity("1.5e-".to_string()))
+        );
+        assert_eq!(parse_quantity("5 e"), Ok((5.0, "e".to_string())));
+        assert_eq!(
+            parse_quantity("1.5"),
+            Err(ConversionError::InvalidQuantity("1.5".to_string()))
+        );
+        assert_eq!(
+            parse_quantity("1.5 "),
+            Err(ConversionError::InvalidQuantity("1.5 ".to_string()))
+        );
+        assert_eq!(
+            parse_quantity("nm"),
+            Err(ConversionError::InvalidQuantity("nm".to_string()))
+        );
+        let q: Quantity = "2.0 eV".parse().unwrap();
+        assert_eq!(q, Quantity::new(2.0, "eV"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let q = Quantity::new(1.5, "nm");
+        let json = serde_json::to_string(&q).unwrap();
+        assert_eq!(json, r#"{"value":1.5,"unit":"nm"}"#);
+        assert_eq!(serde_json::from_str::<Quantity>(&json).unwrap(), q);
+        let unknown = serde_json::from_str::<Quantity>(r#"{"value":1.5,"unit":"parsnip"}"#);
+        assert!(unknown.unwrap_err().to_string().contains("parsnip"));
+    }
+}
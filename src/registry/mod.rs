@@ -0,0 +1,217 @@
+//! This module offers a registry of units that can be extended with custom units at runtime.
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use core::fmt;
+
+use crate::collections::Map;
+use crate::dispatch::{preferred_category, unprefixed, Reading};
+use crate::lookup::get_converter;
+use crate::{Category, ConversionError};
+
+/// A conversion function registered with [`UnitRegistry::register_fn`].
+pub type ConversionFn = Box<dyn Fn(f64) -> f64 + Send + Sync>;
+
+/// The conversion of a custom unit to and from the base unit of its category.
+#[derive(Clone)]
+enum CustomUnit {
+    Factor(f64),
+    Functions {
+        to_base: Arc<dyn Fn(f64) -> f64 + Send + Sync>,
+        from_base: Arc<dyn Fn(f64) -> f64 + Send + Sync>,
+    },
+}
+
+impl fmt::Debug for CustomUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CustomUnit::Factor(factor) => f.debug_tuple("Factor").field(factor).finish(),
+            CustomUnit::Functions { .. } => f.write_str("Functions"),
+        }
+    }
+}
+
+/// The built-in units together with custom units registered at runtime.
+///
+/// A custom unit is given by its size in the base unit of its category, e.g. metres for lengths,
+/// or by functions converting to and from the base unit, and converts to and from every other
+/// unit of the category. Custom units take precedence over built-in units of the same name.
+#[derive(Debug, Clone, Default)]
+pub struct UnitRegistry {
+    custom: Map<Category, Map<String, CustomUnit>>,
+}
+
+impl UnitRegistry {
+    /// Creates a registry knowing the built-in units only.
+    pub fn new() -> Self {
+        UnitRegistry::default()
+    }
+
+    /// Registers the unit `name` in `category`, one of which is `to_base_factor` base units of
+    /// the category.
+    pub fn register(&mut self, category: Category, name: &str, to_base_factor: f64) {
+        self.insert(category, name, CustomUnit::Factor(to_base_factor));
+    }
+
+    /// Registers the unit `name` in `category` with functions converting a value in the unit to
+    /// the base unit of the category and back, e.g. for a nonlinear detector response. The
+    /// functions are taken to be inverse to each other.
+    pub fn register_fn(
+        &mut self,
+        category: Category,
+        name: &str,
+        to_base: ConversionFn,
+        from_base: ConversionFn,
+    ) {
+        let functions = CustomUnit::Functions {
+            to_base: Arc::from(to_base),
+            from_base: Arc::from(from_base),
+        };
+        self.insert(category, name, functions);
+    }
+
+    fn insert(&mut self, category: Category, name: &str, unit: CustomUnit) {
+        self.custom
+            .entry(category)
+            .or_default()
+            .insert(name.to_string(), unit);
+    }
+
+    fn custom_unit(&self, category: Category, unit: &str) -> Option<&CustomUnit> {
+        self.custom.get(&category)?.get(unit)
+    }
+
+    fn is_custom(&self, unit: &str) -> bool {
+        self.custom.values().any(|units| units.contains_key(unit))
+    }
+
+    /// Returns the unit without an SI prefix like [`unprefixed`], leaving custom units as they
+    /// are.
+    fn unprefixed<'a>(&self, unit: &'a str) -> Reading<'a> {
+        if self.is_custom(unit) {
+            (1.0, unit)
+        } else {
+            unprefixed(unit)
+        }
+    }
+
+    fn knows(&self, category: Category, unit: &str) -> bool {
+        self.custom_unit(category, unit).is_some() || category.contains(unit)
+    }
+
+    fn to_base(&self, category: Category, value: f64, unit: &str) -> Result<f64, ConversionError> {
+        match self.custom_unit(category, unit) {
+            Some(CustomUnit::Factor(factor)) => Ok(value * factor),
+            Some(CustomUnit::Functions { to_base, .. }) => Ok(to_base(value)),
+            None => get_converter(category.to_base_map(), unit)?(value, unit),
+        }
+    }
+
+    fn to_unit(&self, category: Category, value: f64, unit: &str) -> Result<f64, ConversionError> {
+        match self.custom_unit(category, unit) {
+            Some(CustomUnit::Factor(factor)) => Ok(value / factor),
+            Some(CustomUnit::Functions { from_base, .. }) => Ok(from_base(value)),
+            None => get_converter(category.to_unit_map(), unit)?(value, unit),
+        }
+    }
+
+    /// Converts `value` from `from` to `to` like [`crate::convert`], treating the custom units
+    /// like built-in ones. Built-in units may carry an SI prefix like `km`, and pairs known to
+    /// several categories are converted in the category [`crate::convert`] would use.
+    pub fn convert(&self, value: f64, from: &str, to: &str) -> Result<f64, ConversionError> {
+        if !self.is_custom(from) && !self.is_custom(to) {
+            return crate::convert(value, from, to);
+        }
+        let ((from_scale, from_unit), (to_scale, to_unit)) =
+            (self.unprefixed(from), self.unprefixed(to));
+        if let Some(category) =
+            preferred_category(|c| self.knows(c, from_unit) && self.knows(c, to_unit))
+        {
+            let base = self.to_base(category, value * from_scale, from_unit)?;
+            return Ok(self.to_unit(category, base, to_unit)? / to_scale);
+        }
+        let (from, to) = (from_unit, to_unit);
+        let category_of = |unit: &str| {
+            Category::ALL
+                .into_iter()
+                .find(|&c| self.knows(c, unit))
+                .ok_or_else(|| ConversionError::UnknownUnit(unit.to_string()))
+        };
+        Err(ConversionError::DimensionMismatch {
+            from_category: category_of(from)?,
+            to_category: category_of(to)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+    use approx::assert_relative_eq;
+    use num_traits::Float;
+
+    use super::*;
+    use crate::codata::expected;
+
+    #[test]
+    fn custom_length_unit() {
+        let mut registry = UnitRegistry::new();
+        registry.register(Category::Length, "smoot", 1.702);
+        assert_relative_eq!(registry.convert(1.0, "smoot", "m").unwrap(), 1.702);
+        assert_relative_eq!(registry.convert(3.404, "m", "smoot").unwrap(), 2.0);
+        assert_relative_eq!(registry.convert(1.0, "smoot", "cm").unwrap(), 170.2);
+        assert_relative_eq!(
+            registry.convert(1.0, "bohr", "ang").unwrap(),
+            expected::BOHR_RADIUS_IN_ANGSTROM
+        );
+        assert_eq!(
+            registry.convert(1.0, "smoot", "eV"),
+            Err(ConversionError::DimensionMismatch {
+                from_category: Category::Length,
+                to_category: Category::Energy,
+            })
+        );
+        assert!(crate::convert(1.0, "smoot", "m").is_err());
+    }
+
+    #[test]
+    fn built_in_units_convert_like_crate_convert() {
+        let mut registry = UnitRegistry::new();
+        registry.register(Category::Length, "smoot", 1.702);
+        assert_eq!(
+            registry.convert(1.0, "Hz", "rad/s"),
+            Ok(core::f64::consts::TAU)
+        );
+        assert_eq!(registry.convert(1.0, "km", "m"), Ok(1e3));
+        assert_relative_eq!(registry.convert(1.702, "km", "smoot").unwrap(), 1e3);
+        assert_relative_eq!(registry.convert(1e3, "smoot", "km").unwrap(), 1.702);
+        assert_relative_eq!(
+            registry.convert(2.0, "keV", "nm").unwrap(),
+            crate::convert(2.0, "keV", "nm").unwrap()
+        );
+    }
+
+    #[test]
+    fn custom_nonlinear_unit() {
+        let mut registry = UnitRegistry::new();
+        // A detector reading the square root of the incident power.
+        registry.register_fn(
+            Category::Power,
+            "reading",
+            Box::new(|x| x * x),
+            Box::new(Float::sqrt),
+        );
+        assert_relative_eq!(registry.convert(3.0, "reading", "W").unwrap(), 9.0);
+        assert_relative_eq!(registry.convert(0.4, "kW", "reading").unwrap(), 20.0);
+        let copy = registry.clone();
+        assert_relative_eq!(copy.convert(2.0, "reading", "reading").unwrap(), 2.0);
+        assert_eq!(
+            registry.convert(1.0, "reading", "m"),
+            Err(ConversionError::DimensionMismatch {
+                from_category: Category::Power,
+                to_category: Category::Length,
+            })
+        );
+    }
+}
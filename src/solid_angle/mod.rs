@@ -0,0 +1,48 @@
+//! This module offers solid angle unit conversion functions in double precision, i.e. f64.
+//! A square degree is a solid angle of (π/180)² sr.
+
+use core::f64::consts::PI;
+
+use crate::table::conversion_table;
+
+const SQUARE_DEGREE_TO_STERADIANS: f64 = (PI / 180.0) * (PI / 180.0);
+
+conversion_table!(
+    kind = "solid angle",
+    category = SolidAngle,
+    a_kind = "a solid angle",
+    base = "steradians",
+    /// Returns the size of the solid angle unit in steradians.
+    factor = steradians_per,
+    to_base = to_steradians,
+    from_base = from_steradians,
+    to_map = convert_2_sr_from,
+    from_map = convert_from_sr_2,
+    units = {
+        "sr" => 1.0,
+        "deg2" => SQUARE_DEGREE_TO_STERADIANS,
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::photometry::FULL_SPHERE_STERADIANS;
+
+    #[test]
+    fn sphere_and_square_degrees() {
+        assert_relative_eq!(FULL_SPHERE_STERADIANS, 4.0 * PI);
+        assert_relative_eq!(
+            try_convert(FULL_SPHERE_STERADIANS, "sr", "deg2").unwrap(),
+            41_252.96,
+            max_relative = 1e-6
+        );
+        assert_relative_eq!(
+            try_convert(1.0, "sr", "deg2").unwrap(),
+            3_282.8,
+            max_relative = 1e-5
+        );
+    }
+}
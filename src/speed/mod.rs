@@ -0,0 +1,107 @@
+//! This module offers speed unit conversion functions in double precision, i.e. f64.
+//! For wind speeds it also maps between speeds and the Beaufort scale.
+
+use num_traits::Float;
+
+use crate::table::conversion_table;
+use crate::ConversionError;
+
+const KILOMETRES_PER_HOUR_TO_MPS: f64 = 1.0 / 3.6;
+const MILES_PER_HOUR_TO_MPS: f64 = 0.447_04;
+const KNOT_TO_MPS: f64 = 0.514_444_444;
+const SPEED_OF_LIGHT: f64 = 299_792_458.0;
+
+conversion_table!(
+    kind = "speed",
+    category = Speed,
+    a_kind = "a speed",
+    base = "metres per second",
+    /// Returns the size of the speed unit in metres per second.
+    factor = mps_per,
+    to_base = to_mps,
+    from_base = from_mps,
+    to_map = convert_2_mps_from,
+    from_map = convert_from_mps_2,
+    units = {
+        "m/s" => 1.0,
+        "km/h" => KILOMETRES_PER_HOUR_TO_MPS,
+        "mph" => MILES_PER_HOUR_TO_MPS,
+        "kn" => KNOT_TO_MPS,
+        "c" => SPEED_OF_LIGHT,
+    }
+);
+
+/// Highest number of the Beaufort scale, hurricane force.
+pub const BEAUFORT_MAX: u8 = 12;
+/// Factor of the empirical Beaufort relation v = 0.836·B^1.5 m/s.
+const BEAUFORT_FACTOR: f64 = 0.836;
+/// Wind speed in m/s hurricane force starts at.
+const HURRICANE_FORCE_MPS: f64 = 32.7;
+
+/// Function to convert a Beaufort number to a representative wind speed.
+/// # Arguments
+///  * `beaufort` - Beaufort number, numbers above 12 are taken as 12
+///  * `unit` - speed unit of the result, e.g. `kn`
+///
+/// Numbers up to 11 give the speed 0.836·B^1.5 m/s at the centre of their band. Hurricane force
+/// has no upper bound, so 12 gives the speed it starts at, 32.7 m/s.
+pub fn beaufort_to_speed(beaufort: u8, unit: &str) -> Result<f64, ConversionError> {
+    let mps = if beaufort < BEAUFORT_MAX {
+        BEAUFORT_FACTOR * Float::powf(f64::from(beaufort), 1.5)
+    } else {
+        HURRICANE_FORCE_MPS
+    };
+    try_convert(mps, "m/s", unit)
+}
+
+/// Function to convert a wind speed to the nearest Beaufort number.
+/// # Arguments
+///  * `speed` - wind speed
+///  * `unit` - speed unit of the value, e.g. `km/h`
+///
+/// The number is rounded and clamped to 0 to 12, so calm and negative speeds give 0 and speeds
+/// beyond hurricane force give 12. Returns `NonFinite` if the speed is NaN.
+pub fn speed_to_beaufort(speed: f64, unit: &str) -> Result<u8, ConversionError> {
+    let mps = try_convert(speed, unit, "m/s")?;
+    if mps.is_nan() {
+        return Err(ConversionError::NonFinite);
+    }
+    let mps = mps.max(0.0);
+    let beaufort = Float::powf(mps / BEAUFORT_FACTOR, 2.0 / 3.0);
+    Ok(Float::round(beaufort).min(f64::from(BEAUFORT_MAX)) as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn light_speed_and_road_units() {
+        assert_eq!(try_convert(1.0, "c", "m/s"), Ok(299_792_458.0));
+        assert_relative_eq!(try_convert(3.6, "km/h", "m/s").unwrap(), 1.0);
+        assert_relative_eq!(try_convert(1.0, "mph", "km/h").unwrap(), 1.609_344);
+    }
+
+    #[test]
+    fn beaufort_scale() {
+        assert_relative_eq!(beaufort_to_speed(12, "m/s").unwrap(), 32.7);
+        assert_eq!(speed_to_beaufort(10.0, "m/s"), Ok(5));
+        assert_relative_eq!(beaufort_to_speed(4, "m/s").unwrap(), 6.688);
+        assert_eq!(beaufort_to_speed(15, "kn"), beaufort_to_speed(12, "kn"));
+        assert_eq!(speed_to_beaufort(0.0, "kn"), Ok(0));
+        assert_eq!(speed_to_beaufort(-3.0, "m/s"), Ok(0));
+        assert_eq!(speed_to_beaufort(300.0, "km/h"), Ok(12));
+        assert_eq!(speed_to_beaufort(33.0, "m/s"), Ok(12));
+        assert_eq!(
+            speed_to_beaufort(f64::NAN, "m/s"),
+            Err(ConversionError::NonFinite)
+        );
+        assert_eq!(speed_to_beaufort(f64::INFINITY, "kn"), Ok(12));
+        for beaufort in 0..=BEAUFORT_MAX {
+            let speed = beaufort_to_speed(beaufort, "km/h").unwrap();
+            assert_eq!(speed_to_beaufort(speed, "km/h"), Ok(beaufort));
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! This module offers the lazily initialised cell holding the conversion tables.
+//! With the `std` feature it is `std::sync::OnceLock`; without it a minimal cell built on an
+//! atomic pointer is used instead.
+
+#[cfg(feature = "std")]
+pub(crate) use std::sync::OnceLock;
+
+#[cfg(not(feature = "std"))]
+pub(crate) use race::OnceLock;
+
+#[cfg(not(feature = "std"))]
+mod race {
+    use alloc::boxed::Box;
+    use core::marker::PhantomData;
+    use core::ptr;
+    use core::sync::atomic::{AtomicPtr, Ordering};
+
+    /// A cell written at most once, intended for statics. Concurrent first accesses may each run
+    /// the initialiser, but only one result is stored and every caller sees that one. The value
+    /// is leaked, which is fine for statics as they are never dropped.
+    pub(crate) struct OnceLock<T> {
+        value: AtomicPtr<T>,
+        _marker: PhantomData<Box<T>>,
+    }
+
+    impl<T> OnceLock<T> {
+        pub(crate) const fn new() -> Self {
+            OnceLock {
+                value: AtomicPtr::new(ptr::null_mut()),
+                _marker: PhantomData,
+            }
+        }
+
+        pub(crate) fn get_or_init(&self, init: impl FnOnce() -> T) -> &T {
+            let current = self.value.load(Ordering::Acquire);
+            if !current.is_null() {
+                // SAFETY: a non-null pointer was stored from a leaked box and is never freed.
+                return unsafe { &*current };
+            }
+            let new = Box::into_raw(Box::new(init()));
+            match self.value.compare_exchange(
+                ptr::null_mut(),
+                new,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                // SAFETY: `new` comes from `Box::into_raw` and is now owned by the cell.
+                Ok(_) => unsafe { &*new },
+                Err(stored) => {
+                    // SAFETY: `new` was not published, so this is its only owner.
+                    drop(unsafe { Box::from_raw(new) });
+                    // SAFETY: see above, the stored pointer is never freed.
+                    unsafe { &*stored }
+                }
+            }
+        }
+    }
+}
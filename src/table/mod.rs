@@ -0,0 +1,274 @@
+//! This module offers the `conversion_table!` macro generating the conversion functions and maps
+//! of a category whose units are all proportional to its base unit.
+
+/// Generates the conversion boilerplate of a category from its base unit and a list of units
+/// with their size in the base unit:
+///
+///  * a private factor function returning the size of a unit, or `UnknownUnit`,
+///  * the public functions converting to and from the base unit, taking the unit as prefix,
+///  * the public getters of the map to the base unit and of the derived inverse map,
+///  * the category's marker type implementing [`crate::category::UnitCategory`],
+///  * the category's `try_convert`, composing the two conversions of the marker type.
+///
+/// The conversion from the base unit is derived by dividing by the factor, so only one direction
+/// is written down. Units with an offset zero, e.g. gauge pressures, can be listed in an optional
+/// `affine` section with their [`crate::affine::AffineConversion`]; they are exposed through the
+/// category's `affine_units` map and converted by it.
+macro_rules! conversion_table {
+    (
+        kind = $kind:literal,
+        category = $category:ident,
+        a_kind = $a_kind:literal,
+        base = $base:literal,
+        $(#[$factor_meta:meta])*
+        factor = $factor:ident,
+        to_base = $to_base:ident,
+        from_base = $from_base:ident,
+        to_map = $to_map:ident,
+        from_map = $from_map:ident,
+        units = {
+            $first:literal => $first_value:expr
+            $(, $unit:literal => $value:expr)* $(,)?
+        } $(,)?
+        $(affine = {
+            $($affine_unit:literal => $affine:expr),+ $(,)?
+        } $(,)?)?
+    ) => {
+        $(#[$factor_meta])*
+        fn $factor(unit: &str) -> Result<f64, $crate::ConversionError> {
+            match unit {
+                $first => Ok($first_value),
+                $($unit => Ok($value),)*
+                _ => Err($crate::ConversionError::UnknownUnit(
+                    ::alloc::string::ToString::to_string(unit),
+                )),
+            }
+        }
+
+        #[doc = concat!("Function to convert ", $a_kind, " to ", $base, ".")]
+        #[doc = "# Arguments"]
+        #[doc = " * `v` - value to convert"]
+        #[doc = concat!(" * `prefix` - can be one of [`", $first, "`", $(", `", $unit, "`",)* "].")]
+        #[doc = "   Gives the unit of the value."]
+        pub fn $to_base(v: f64, prefix: &str) -> Result<f64, $crate::ConversionError> {
+            Ok(v * $factor(prefix)?)
+        }
+
+        #[doc = concat!("Function to convert ", $base, " to another ", $kind, " unit.")]
+        #[doc = "# Arguments"]
+        #[doc = concat!(" * `v` - value in ", $base, " to convert")]
+        #[doc = concat!(" * `prefix` - can be one of [`", $first, "`", $(", `", $unit, "`",)* "].")]
+        #[doc = "   Gives the unit of the result."]
+        pub fn $from_base(v: f64, prefix: &str) -> Result<f64, $crate::ConversionError> {
+            Ok(v / $factor(prefix)?)
+        }
+
+        pub fn $to_map(
+        ) -> &'static $crate::collections::Map<&'static str, $crate::category::Callback> {
+            static MAP: $crate::sync::OnceLock<
+                $crate::collections::Map<&'static str, $crate::category::Callback>,
+            > = $crate::sync::OnceLock::new();
+            MAP.get_or_init(|| {
+                let mut t = $crate::collections::Map::new();
+                t.insert($first, $to_base as $crate::category::Callback);
+                $(t.insert($unit, $to_base as $crate::category::Callback);)*
+                $($(t.insert($affine_unit, affine_to_base as $crate::category::Callback);)+)?
+                t
+            })
+        }
+
+        pub fn $from_map(
+        ) -> &'static $crate::collections::Map<&'static str, $crate::category::Callback> {
+            struct ToBase;
+
+            impl $crate::inverse::Forward for ToBase {
+                fn forward(
+                ) -> &'static $crate::collections::Map<&'static str, $crate::category::Callback>
+                {
+                    $to_map()
+                }
+            }
+
+            static MAP: $crate::sync::OnceLock<
+                $crate::collections::Map<&'static str, $crate::category::Callback>,
+            > = $crate::sync::OnceLock::new();
+            MAP.get_or_init(|| {
+                $crate::inverse::invert::<ToBase>(&[
+                    $($(($affine_unit, affine_from_base as $crate::category::Callback)),+)?
+                ])
+            })
+        }
+
+        $(
+            #[doc = concat!("Returns the ", $kind, " units whose zero is offset from the zero of ", $base, ", so that")]
+            #[doc = concat!("they can not be converted with a single factor, with their conversion to ", $base, ".")]
+            pub fn affine_units() -> &'static $crate::collections::Map<
+                &'static str,
+                $crate::affine::AffineConversion,
+            > {
+                static MAP: $crate::sync::OnceLock<
+                    $crate::collections::Map<&'static str, $crate::affine::AffineConversion>,
+                > = $crate::sync::OnceLock::new();
+                MAP.get_or_init(|| {
+                    let mut t = $crate::collections::Map::new();
+                    $(t.insert($affine_unit, $affine);)+
+                    t
+                })
+            }
+
+            fn affine_conversion(
+                unit: &str,
+            ) -> Result<$crate::affine::AffineConversion, $crate::ConversionError> {
+                affine_units().get(unit).copied().ok_or_else(|| {
+                    $crate::ConversionError::UnknownUnit(::alloc::string::ToString::to_string(unit))
+                })
+            }
+
+            fn affine_to_base(v: f64, unit: &str) -> Result<f64, $crate::ConversionError> {
+                Ok(affine_conversion(unit)?.to_base(v))
+            }
+
+            fn affine_from_base(v: f64, unit: &str) -> Result<f64, $crate::ConversionError> {
+                Ok(affine_conversion(unit)?.from_base(v))
+            }
+        )?
+
+        #[doc = concat!("The ", $kind, " category, whose units are converted through ", $base, ".")]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $category;
+
+        impl $crate::category::UnitCategory for $category {
+            fn to_base(value: f64, unit: &str) -> Result<f64, $crate::ConversionError> {
+                $crate::lookup::get_converter($to_map(), unit)?(value, unit)
+            }
+
+            fn from_base(value: f64, unit: &str) -> Result<f64, $crate::ConversionError> {
+                $crate::lookup::get_converter($from_map(), unit)?(value, unit)
+            }
+        }
+
+        #[doc = concat!("Converts `value` from the ", $kind, " unit `from` to the ", $kind, " unit `to` by going")]
+        #[doc = concat!("through ", $base, ". Returns `UnknownUnit` if either unit is not registered.")]
+        pub fn try_convert(
+            value: f64,
+            from: &str,
+            to: &str,
+        ) -> Result<f64, $crate::ConversionError> {
+            $crate::category::convert_within::<$category>(value, from, to)
+        }
+    };
+}
+
+pub(crate) use conversion_table;
+
+#[cfg(test)]
+mod tests {
+    mod length_table {
+        use crate::length::{
+            ANGSTROEM, ASTRONOMICAL_UNIT_TO_METRES, BOHR_RADIUS_TO_METRES, FOOT_TO_METRES,
+            INCH_TO_METRES, LIGHT_YEAR_TO_METRES, MILE_TO_METRES, PARSEC_TO_METRES, YARD_TO_METRES,
+        };
+
+        conversion_table!(
+            kind = "length",
+            category = Length,
+            a_kind = "a length",
+            base = "metres",
+            factor = metres_per,
+            to_base = to_metres,
+            from_base = from_metres,
+            to_map = convert_2_m_from,
+            from_map = convert_from_m_2,
+            units = {
+                "m" => 1.0,
+                "bohr" => BOHR_RADIUS_TO_METRES,
+                "ang" => ANGSTROEM,
+                "cm" => 1e-2,
+                "mm" => 1e-3,
+                "mu" => 1e-6,
+                "µm" => 1e-6,
+                "μm" => 1e-6,
+                "um" => 1e-6,
+                "micron" => 1e-6,
+                "nm" => 1e-9,
+                "pm" => 1e-12,
+                "fm" => 1e-15,
+                "in" => INCH_TO_METRES,
+                "ft" => FOOT_TO_METRES,
+                "yd" => YARD_TO_METRES,
+                "mi" => MILE_TO_METRES,
+                "AU" => ASTRONOMICAL_UNIT_TO_METRES,
+                "ly" => LIGHT_YEAR_TO_METRES,
+                "pc" => PARSEC_TO_METRES,
+            }
+        );
+
+        #[test]
+        fn reproduces_the_length_maps() {
+            let hand_written = crate::length::convert_to_metres_from();
+            assert_eq!(convert_2_m_from().len(), hand_written.len());
+            for (unit, to_metres) in hand_written {
+                assert_eq!(convert_2_m_from()[unit](2.5, unit), to_metres(2.5, unit));
+                assert_eq!(
+                    convert_from_m_2()[unit](2.5, unit),
+                    crate::length::convert_metres_to()[unit](2.5, unit)
+                );
+                assert_eq!(from_metres(2.5, unit), convert_from_m_2()[unit](2.5, unit));
+            }
+            assert_eq!(
+                try_convert(1.0, "mi", "ft"),
+                crate::length::try_convert(1.0, "mi", "ft")
+            );
+        }
+    }
+
+    mod energy_table {
+        use alloc::string::ToString;
+        use approx::assert_relative_eq;
+
+        use crate::energy::{
+            EV_JOULE_CONVERSION_FACTOR, HARTREE_EV_CONVERSION_FACTOR,
+            REC_CENTIMETRES_EV_CONVERSION_FACTOR, RYDBERG_EV_CONVERSION_FACTOR,
+        };
+
+        conversion_table!(
+            kind = "energy",
+            category = Energy,
+            a_kind = "an energy",
+            base = "electronvolt",
+            factor = electronvolts_per,
+            to_base = to_ev,
+            from_base = from_ev,
+            to_map = convert_2_ev_from,
+            from_map = convert_from_ev_2,
+            units = {
+                "eV" => 1.0,
+                "rcm" => REC_CENTIMETRES_EV_CONVERSION_FACTOR,
+                "hartree" => HARTREE_EV_CONVERSION_FACTOR,
+                "Ry" => RYDBERG_EV_CONVERSION_FACTOR,
+                "J" => 1.0 / EV_JOULE_CONVERSION_FACTOR,
+                "kJ" => 1e3 / EV_JOULE_CONVERSION_FACTOR,
+            }
+        );
+
+        #[test]
+        fn reproduces_the_proportional_energy_units() {
+            let hand_written = crate::energy::convert_2_ev_from();
+            for (unit, to_ev) in convert_2_ev_from() {
+                let expected = hand_written[unit](2.5, unit).unwrap();
+                assert_relative_eq!(to_ev(2.5, unit).unwrap(), expected, max_relative = 1e-15);
+                let expected = crate::energy::convert_from_ev_2()[unit](2.5, unit).unwrap();
+                assert_relative_eq!(
+                    convert_from_ev_2()[unit](2.5, unit).unwrap(),
+                    expected,
+                    max_relative = 1e-9
+                );
+                assert_eq!(from_ev(2.5, unit), convert_from_ev_2()[unit](2.5, unit));
+            }
+            assert_eq!(
+                try_convert(1.0, "nm", "eV"),
+                Err(crate::ConversionError::UnknownUnit("nm".to_string()))
+            );
+        }
+    }
+}
@@ -0,0 +1,187 @@
+//! This module offers temperature unit conversion functions in double precision, i.e. f64.
+//! Temperature scales differ by an offset, so unlike the other modules the conversions are affine
+//! (`v * factor + offset`) rather than a plain multiplication. Kelvin is the base unit.
+
+use crate::affine::AffineConversion;
+use crate::category::{convert_within, Callback, UnitCategory};
+use crate::collections::Map;
+use crate::energy;
+use crate::lookup::get_converter;
+use crate::sync::OnceLock;
+use crate::ConversionError;
+
+const CELSIUS_KELVIN_OFFSET: f64 = 273.15;
+const FAHRENHEIT_CELSIUS_OFFSET: f64 = 32.0;
+/// Size of one degree Fahrenheit or Rankine in kelvin.
+const FAHRENHEIT_DEGREE: f64 = 5.0 / 9.0;
+
+/// Conversion of degree Celsius to kelvin.
+const CELSIUS: AffineConversion = AffineConversion::new(1.0, CELSIUS_KELVIN_OFFSET);
+/// Conversion of degree Fahrenheit to kelvin.
+const FAHRENHEIT: AffineConversion = AffineConversion::new(
+    FAHRENHEIT_DEGREE,
+    CELSIUS_KELVIN_OFFSET - FAHRENHEIT_CELSIUS_OFFSET * FAHRENHEIT_DEGREE,
+);
+
+fn unity(temperature: f64, _prefix: &str) -> Result<f64, ConversionError> {
+    Ok(temperature)
+}
+
+/// Converts a temperature in degree Celsius to kelvin.
+pub fn celsius_2_kelvin(temperature: f64, _prefix: &str) -> Result<f64, ConversionError> {
+    Ok(CELSIUS.to_base(temperature))
+}
+
+/// Converts a temperature in kelvin to degree Celsius.
+pub fn kelvin_2_celsius(temperature: f64, _prefix: &str) -> Result<f64, ConversionError> {
+    Ok(CELSIUS.from_base(temperature))
+}
+
+/// Converts a temperature in degree Fahrenheit to degree Celsius.
+pub fn fahrenheit_2_celsius(temperature: f64, _prefix: &str) -> Result<f64, ConversionError> {
+    Ok((temperature - FAHRENHEIT_CELSIUS_OFFSET) * FAHRENHEIT_DEGREE)
+}
+
+/// Converts a temperature in degree Celsius to degree Fahrenheit.
+pub fn celsius_2_fahrenheit(temperature: f64, _prefix: &str) -> Result<f64, ConversionError> {
+    Ok(temperature / FAHRENHEIT_DEGREE + FAHRENHEIT_CELSIUS_OFFSET)
+}
+
+/// Converts a temperature in degree Fahrenheit to kelvin.
+pub fn fahrenheit_2_kelvin(temperature: f64, _prefix: &str) -> Result<f64, ConversionError> {
+    Ok(FAHRENHEIT.to_base(temperature))
+}
+
+/// Converts a temperature in kelvin to degree Fahrenheit.
+pub fn kelvin_2_fahrenheit(temperature: f64, _prefix: &str) -> Result<f64, ConversionError> {
+    Ok(FAHRENHEIT.from_base(temperature))
+}
+
+/// Converts a temperature in degree Rankine to kelvin. Rankine shares its zero with kelvin.
+pub fn rankine_2_kelvin(temperature: f64, _prefix: &str) -> Result<f64, ConversionError> {
+    Ok(temperature * FAHRENHEIT_DEGREE)
+}
+
+/// Converts a temperature in kelvin to degree Rankine.
+pub fn kelvin_2_rankine(temperature: f64, _prefix: &str) -> Result<f64, ConversionError> {
+    Ok(temperature / FAHRENHEIT_DEGREE)
+}
+
+/// Returns the temperature units whose zero is offset from the absolute zero, so that they can
+/// not be converted with a single factor, with their conversion to kelvin.
+pub fn affine_units() -> &'static Map<&'static str, AffineConversion> {
+    static MAP: OnceLock<Map<&'static str, AffineConversion>> = OnceLock::new();
+    MAP.get_or_init(|| {
+        let mut t = Map::new();
+        t.insert("degC", CELSIUS);
+        t.insert("degF", FAHRENHEIT);
+        t
+    })
+}
+
+pub fn convert_2_kelvin_from() -> &'static Map<&'static str, Callback> {
+    static MAP: OnceLock<Map<&'static str, Callback>> = OnceLock::new();
+    MAP.get_or_init(|| {
+        let mut t = Map::new();
+        t.insert("K", unity as Callback);
+        t.insert("degC", celsius_2_kelvin as Callback);
+        t.insert("degF", fahrenheit_2_kelvin as Callback);
+        t.insert("degR", rankine_2_kelvin as Callback);
+        t
+    })
+}
+
+pub fn convert_from_kelvin_2() -> &'static Map<&'static str, Callback> {
+    static MAP: OnceLock<Map<&'static str, Callback>> = OnceLock::new();
+    MAP.get_or_init(|| {
+        let mut t = Map::new();
+        t.insert("K", unity as Callback);
+        t.insert("degC", kelvin_2_celsius as Callback);
+        t.insert("degF", kelvin_2_fahrenheit as Callback);
+        t.insert("degR", kelvin_2_rankine as Callback);
+        t
+    })
+}
+
+/// The temperature category, whose units are converted through kelvin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Temperature;
+
+impl UnitCategory for Temperature {
+    fn to_base(value: f64, unit: &str) -> Result<f64, ConversionError> {
+        get_converter(convert_2_kelvin_from(), unit)?(value, unit)
+    }
+
+    fn from_base(value: f64, unit: &str) -> Result<f64, ConversionError> {
+        get_converter(convert_from_kelvin_2(), unit)?(value, unit)
+    }
+}
+
+/// Converts `value` from the temperature unit `from` to the temperature unit `to` by going
+/// through kelvin. Returns `UnknownUnit` if either unit is not registered.
+pub fn try_convert(value: f64, from: &str, to: &str) -> Result<f64, ConversionError> {
+    convert_within::<Temperature>(value, from, to)
+}
+
+/// Converts an absolute temperature in kelvin to its thermal energy equivalent k_B·T in the
+/// energy unit `energy_unit`, e.g. `temperature_to_energy(300.0, "rcm")` gives about 208.5 cm⁻¹.
+///
+/// Temperature and energy are separate categories, so [`crate::convert`] never crosses between
+/// them; this equivalence has to be asked for explicitly. Returns `UnknownUnit` if `energy_unit` is
+/// not an energy unit.
+pub fn temperature_to_energy(kelvin: f64, energy_unit: &str) -> Result<f64, ConversionError> {
+    energy::try_convert(energy::kelvin_2_ev(kelvin, "")?, "eV", energy_unit)
+}
+
+/// Converts an energy in the energy unit `energy_unit` to the absolute temperature in kelvin at
+/// which k_B·T equals that energy; the inverse of [`temperature_to_energy`].
+pub fn energy_to_temperature(energy: f64, energy_unit: &str) -> Result<f64, ConversionError> {
+    energy::ev_2_kelvin(energy::try_convert(energy, energy_unit, "eV")?, "")
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn classic_checkpoints() {
+        assert_relative_eq!(-40.0, try_convert(-40.0, "degC", "degF").unwrap());
+        assert_relative_eq!(212.0, try_convert(100.0, "degC", "degF").unwrap());
+        assert_relative_eq!(273.15, convert_2_kelvin_from()["degC"](0.0, "").unwrap());
+        assert_relative_eq!(491.67, try_convert(0.0, "degC", "degR").unwrap());
+        assert_relative_eq!(37.0, try_convert(98.6, "degF", "degC").unwrap());
+    }
+
+    #[test]
+    fn thermal_energy_crossover() {
+        assert_relative_eq!(
+            11_604.5,
+            energy_to_temperature(1.0, "eV").unwrap(),
+            max_relative = 1e-5
+        );
+        assert_relative_eq!(
+            208.5,
+            temperature_to_energy(300.0, "rcm").unwrap(),
+            max_relative = 1e-3
+        );
+        assert_relative_eq!(
+            300.0,
+            energy_to_temperature(temperature_to_energy(300.0, "hartree").unwrap(), "hartree")
+                .unwrap()
+        );
+        assert_eq!(
+            temperature_to_energy(300.0, "degC"),
+            Err(ConversionError::UnknownUnit("degC".to_string()))
+        );
+        assert_eq!(
+            crate::convert(300.0, "K", "eV"),
+            Err(ConversionError::DimensionMismatch {
+                from_category: crate::Category::Temperature,
+                to_category: crate::Category::Energy,
+            })
+        );
+    }
+}
@@ -0,0 +1,81 @@
+//! This module offers time unit conversion functions in double precision, i.e. f64.
+//! The SI-prefixed seconds mirror the prefix handling of the length module.
+
+use crate::energy::{HARTREE_EV_CONVERSION_FACTOR, REDUCED_PLANCK_CONSTANT_EV};
+use crate::table::conversion_table;
+
+const ATTO: f64 = 1e-18;
+const FEMTO: f64 = 1e-15;
+const PICO: f64 = 1e-12;
+const NANO: f64 = 1e-9;
+const MIKRO: f64 = 1e-6;
+const MILLI: f64 = 1e-3;
+const MINUTE_TO_SECONDS: f64 = 60.0;
+const HOUR_TO_SECONDS: f64 = 3_600.0;
+const DAY_TO_SECONDS: f64 = 86_400.0;
+const JULIAN_YEAR_TO_SECONDS: f64 = 31_557_600.0;
+/// Atomic unit of time ħ/E_h in seconds, derived from the hartree so that the two stay
+/// consistent.
+pub(crate) const ATOMIC_UNIT_OF_TIME_TO_SECONDS: f64 =
+    REDUCED_PLANCK_CONSTANT_EV / HARTREE_EV_CONVERSION_FACTOR;
+
+conversion_table!(
+    kind = "time",
+    category = Time,
+    a_kind = "a time",
+    base = "seconds",
+    /// Returns the size of the (prefixed) time unit in seconds. A year is the Julian year of 365.25
+    /// days.
+    /// The micro prefix may also be spelled `μ` or `u`.
+    factor = seconds_per,
+    to_base = to_seconds,
+    from_base = from_seconds,
+    to_map = convert_2_s_from,
+    from_map = convert_from_s_2,
+    units = {
+        "as" => ATTO,
+        "fs" => FEMTO,
+        "ps" => PICO,
+        "ns" => NANO,
+        "µs" => MIKRO,
+        "μs" => MIKRO,
+        "us" => MIKRO,
+        "ms" => MILLI,
+        "s" => 1.0,
+        "min" => MINUTE_TO_SECONDS,
+        "h" => HOUR_TO_SECONDS,
+        "d" => DAY_TO_SECONDS,
+        "a" => JULIAN_YEAR_TO_SECONDS,
+        "au" => ATOMIC_UNIT_OF_TIME_TO_SECONDS,
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn calendar_and_prefixed_units() {
+        assert_eq!(convert_2_s_from()["d"](1.0, "d"), Ok(86_400.0));
+        assert_eq!(convert_2_s_from()["fs"](1.0, "fs"), Ok(1e-15));
+        assert_relative_eq!(try_convert(1.0, "ps", "fs").unwrap(), 1000.0);
+        assert_relative_eq!(try_convert(1.0, "a", "d").unwrap(), 365.25);
+        assert_relative_eq!(try_convert(90.0, "min", "h").unwrap(), 1.5);
+    }
+
+    #[test]
+    fn atomic_unit_of_time_matches_codata() {
+        assert_relative_eq!(
+            convert_2_s_from()["au"](1.0, "au").unwrap(),
+            2.418_884_326_585_7e-17,
+            max_relative = 1e-4
+        );
+        assert_relative_eq!(
+            try_convert(1.0, "fs", "au").unwrap(),
+            41.341,
+            max_relative = 1e-4
+        );
+    }
+}
@@ -0,0 +1,159 @@
+//! This module offers typed quantities carrying their unit in the type, so that mixing up units
+//! or dimensions is caught by the compiler.
+//!
+//! A `Length<Bohr>` converts into a `Length<Angstroem>` through `From`/`Into`, while converting
+//! it into an energy does not compile:
+//!
+//! ```compile_fail
+//! use unit_conversion::typed::{Bohr, Energy, ElectronVolt, Length};
+//!
+//! let e: Energy<ElectronVolt> = Length::<Bohr>::new(1.0).into();
+//! ```
+//!
+//! The conversions delegate to the conversion maps of the length and energy modules.
+
+use core::marker::PhantomData;
+
+use crate::{energy, length};
+
+/// A unit of length usable in [`Length`].
+pub trait LengthUnit {
+    /// Key of the unit in the conversion maps of the length module.
+    const KEY: &'static str;
+}
+
+/// A unit of energy usable in [`Energy`].
+pub trait EnergyUnit {
+    /// Key of the unit in the conversion maps of the energy module.
+    const KEY: &'static str;
+}
+
+/// A length given in the unit `U`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Length<U> {
+    value: f64,
+    unit: PhantomData<U>,
+}
+
+/// An energy given in the unit `U`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Energy<U> {
+    value: f64,
+    unit: PhantomData<U>,
+}
+
+macro_rules! units {
+    ($unit_trait:ident; $($(#[$doc:meta])* $unit:ident => $key:literal),+ $(,)?) => {
+        $(
+            $(#[$doc])*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+            pub struct $unit;
+
+            impl $unit_trait for $unit {
+                const KEY: &'static str = $key;
+            }
+        )+
+    };
+}
+
+/// Implements `From` between every pair of distinct units of a typed quantity.
+macro_rules! conversions {
+    ($quantity:ident, $unit_trait:ident, $module:ident; $head:ident $(, $tail:ident)*) => {
+        $(
+            conversions!(@from $quantity, $unit_trait, $module; $head => $tail);
+            conversions!(@from $quantity, $unit_trait, $module; $tail => $head);
+        )*
+        conversions!($quantity, $unit_trait, $module; $($tail),*);
+    };
+    ($quantity:ident, $unit_trait:ident, $module:ident;) => {};
+    (@from $quantity:ident, $unit_trait:ident, $module:ident; $from:ident => $to:ident) => {
+        impl From<$quantity<$from>> for $quantity<$to> {
+            fn from(q: $quantity<$from>) -> Self {
+                let value = $module::try_convert(q.value, $from::KEY, $to::KEY)
+                    .expect("typed units are registered in the conversion maps");
+                $quantity::new(value)
+            }
+        }
+    };
+}
+
+macro_rules! quantity {
+    ($quantity:ident, $unit_trait:ident) => {
+        impl<U: $unit_trait> $quantity<U> {
+            pub fn new(value: f64) -> Self {
+                $quantity {
+                    value,
+                    unit: PhantomData,
+                }
+            }
+
+            /// Returns the value in the unit `U`.
+            pub fn value(self) -> f64 {
+                self.value
+            }
+
+            /// Returns the key of the unit `U` in the conversion maps.
+            pub fn unit(self) -> &'static str {
+                U::KEY
+            }
+        }
+    };
+}
+
+quantity!(Length, LengthUnit);
+quantity!(Energy, EnergyUnit);
+
+units!(LengthUnit;
+    /// The Bohr radius a₀, the atomic unit of length.
+    Bohr => "bohr",
+    /// The metre.
+    Metre => "m",
+    /// The angstroem, 1e-10 m.
+    Angstroem => "ang",
+    /// The nanometre.
+    Nanometre => "nm",
+    /// The picometre.
+    Picometre => "pm",
+);
+
+units!(EnergyUnit;
+    /// The electronvolt.
+    ElectronVolt => "eV",
+    /// The wavenumber in cm⁻¹.
+    Wavenumber => "rcm",
+    /// The Hartree, the atomic unit of energy.
+    Hartree => "hartree",
+    /// The Rydberg, half a Hartree.
+    Rydberg => "Ry",
+    /// The joule.
+    Joule => "J",
+    /// The kilojoule per mole.
+    KilojoulePerMole => "kJ/mol",
+);
+
+conversions!(Length, LengthUnit, length; Bohr, Metre, Angstroem, Nanometre, Picometre);
+conversions!(Energy, EnergyUnit, energy; ElectronVolt, Wavenumber, Hartree, Rydberg, Joule, KilojoulePerMole);
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::codata::expected;
+
+    #[test]
+    fn typed_conversions() {
+        let bohr = Length::<Bohr>::new(1.0);
+        let ang: Length<Angstroem> = bohr.into();
+        assert_relative_eq!(ang.value(), expected::BOHR_RADIUS_IN_ANGSTROM);
+        assert_eq!(ang.unit(), "ang");
+        let back: Length<Bohr> = ang.into();
+        assert_relative_eq!(back.value(), 1.0);
+
+        let my_ev = Energy::<ElectronVolt>::new(2.0);
+        let e: Energy<Wavenumber> = my_ev.into();
+        assert_relative_eq!(e.value(), 16_131.087_874);
+        let hartree = Energy::<Hartree>::from(Energy::<Rydberg>::new(2.0));
+        assert_relative_eq!(hartree.value(), 1.0);
+    }
+}
@@ -0,0 +1,139 @@
+//! This module offers the `Unit` enum naming the length, energy and temperature units of the
+//! crate, so conversions can be written without unit strings.
+
+use alloc::string::ToString;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::{Category, ConversionError, Dimension};
+
+macro_rules! units {
+    ($($(#[$doc:meta])* $unit:ident => $key:literal, $symbol:literal),+ $(,)?) => {
+        /// A unit of length, energy or temperature.
+        ///
+        /// `Display` writes the canonical symbol, e.g. `cm⁻¹` or `Å`. `FromStr` parses both the
+        /// key of the unit in the conversion maps, e.g. `rcm` or `ang`, and the symbol.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum Unit {
+            $($(#[$doc])* $unit,)+
+        }
+
+        impl Unit {
+            /// All units.
+            pub const ALL: &'static [Unit] = &[$(Unit::$unit),+];
+
+            /// Returns the key of the unit in the conversion maps, e.g. `rcm`.
+            pub fn key(self) -> &'static str {
+                match self {
+                    $(Unit::$unit => $key,)+
+                }
+            }
+
+            /// Returns the canonical symbol of the unit, e.g. `cm⁻¹`.
+            pub fn symbol(self) -> &'static str {
+                match self {
+                    $(Unit::$unit => $symbol,)+
+                }
+            }
+        }
+    };
+}
+
+units!(
+    Bohr => "bohr", "a₀",
+    Metre => "m", "m",
+    Centimetre => "cm", "cm",
+    Millimetre => "mm", "mm",
+    Micrometre => "µm", "µm",
+    Nanometre => "nm", "nm",
+    Picometre => "pm", "pm",
+    Femtometre => "fm", "fm",
+    Angstroem => "ang", "Å",
+    Inch => "in", "in",
+    Foot => "ft", "ft",
+    Yard => "yd", "yd",
+    Mile => "mi", "mi",
+    AstronomicalUnit => "AU", "AU",
+    LightYear => "ly", "ly",
+    Parsec => "pc", "pc",
+    ElectronVolt => "eV", "eV",
+    /// Wavenumber as a photon energy.
+    Wavenumber => "rcm", "cm⁻¹",
+    /// Wavenumber in reciprocal metres as a photon energy.
+    ReciprocalMetre => "rm", "m⁻¹",
+    /// Kilokayser, 1000 cm⁻¹, as a photon energy.
+    Kilokayser => "kK", "kK",
+    Hartree => "hartree", "Eₕ",
+    Rydberg => "Ry", "Ry",
+    Joule => "J", "J",
+    KilojoulePerMole => "kJ/mol", "kJ/mol",
+    KilocaloriePerMole => "kcal/mol", "kcal/mol",
+    Kelvin => "K", "K",
+    DegreeCelsius => "degC", "°C",
+    DegreeFahrenheit => "degF", "°F",
+    DegreeRankine => "degR", "°R",
+);
+
+impl Unit {
+    /// Returns the dimension of the unit, i.e. that of its category.
+    pub fn dimension(self) -> Dimension {
+        Category::of(self.key())
+            .expect("every unit is registered in a category")
+            .dimension()
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+impl FromStr for Unit {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Unit::ALL
+            .iter()
+            .find(|u| u.key() == s || u.symbol() == s)
+            .copied()
+            .ok_or_else(|| ConversionError::UnknownUnit(s.to_string()))
+    }
+}
+
+/// Converts `value` from the unit `from` to the unit `to` like [`crate::convert`], which remains
+/// the entry point for units given as strings.
+pub fn convert_units(value: f64, from: Unit, to: Unit) -> Result<f64, ConversionError> {
+    crate::convert(value, from.key(), to.key())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::codata::expected;
+
+    #[test]
+    fn symbols_round_trip() {
+        let wavenumber = Unit::from_str("rcm").unwrap();
+        assert_eq!(wavenumber, Unit::Wavenumber);
+        assert_eq!(wavenumber.to_string(), "cm⁻¹");
+        for &unit in Unit::ALL {
+            assert_eq!(unit.to_string().parse::<Unit>(), Ok(unit));
+            assert_eq!(unit.key().parse::<Unit>(), Ok(unit));
+            assert!(Category::of(unit.key()).is_some(), "{}", unit);
+        }
+        assert_eq!(
+            "parsnip".parse::<Unit>(),
+            Err(ConversionError::UnknownUnit("parsnip".to_string()))
+        );
+        assert_eq!(Unit::Kelvin.dimension(), Dimension::TEMPERATURE);
+        assert_eq!(Unit::Hartree.dimension(), Category::Energy.dimension());
+        assert_relative_eq!(
+            convert_units(1.0, Unit::Bohr, Unit::Angstroem).unwrap(),
+            expected::BOHR_RADIUS_IN_ANGSTROM
+        );
+    }
+}
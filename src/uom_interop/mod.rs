@@ -0,0 +1,98 @@
+//! This module offers conversions between [`Quantity`] and the SI quantities of the `uom` crate,
+//! available with the `uom` feature.
+//!
+//! A quantity in any unit of the crate, e.g. bohr, converts into the matching `uom` quantity by
+//! going through the SI unit of its category, e.g. metres. Going back gives a [`Quantity`] in
+//! that SI unit, which can then be converted into any other unit with [`Quantity::convert_to`].
+
+use uom::si::f64::{Energy, Force, Length, Mass, Power, Pressure, ThermodynamicTemperature, Time};
+use uom::si::{energy, force, length, mass, power, pressure, thermodynamic_temperature, time};
+
+use crate::{convert, ConversionError, Quantity};
+
+/// Implements `TryFrom<&Quantity>` and `From<uom quantity> for Quantity` for `uom` quantities
+/// given by their type, their SI unit and the key of that unit in the conversion maps.
+macro_rules! interop {
+    ($($uom_quantity:ident => $si_unit:ty, $key:literal;)+) => {
+        $(
+            impl TryFrom<&Quantity> for $uom_quantity {
+                type Error = ConversionError;
+
+                fn try_from(q: &Quantity) -> Result<Self, Self::Error> {
+                    let value = convert(q.value, &q.unit, $key)?;
+                    Ok($uom_quantity::new::<$si_unit>(value))
+                }
+            }
+
+            impl TryFrom<Quantity> for $uom_quantity {
+                type Error = ConversionError;
+
+                fn try_from(q: Quantity) -> Result<Self, Self::Error> {
+                    $uom_quantity::try_from(&q)
+                }
+            }
+
+            impl From<$uom_quantity> for Quantity {
+                fn from(q: $uom_quantity) -> Self {
+                    Quantity::new(q.get::<$si_unit>(), $key)
+                }
+            }
+        )+
+    };
+}
+
+interop!(
+    Length => length::meter, "m";
+    Energy => energy::joule, "J";
+    Mass => mass::kilogram, "kg";
+    Time => time::second, "s";
+    Pressure => pressure::pascal, "Pa";
+    Force => force::newton, "N";
+    Power => power::watt, "W";
+    ThermodynamicTemperature => thermodynamic_temperature::kelvin, "K";
+);
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::codata::expected;
+    use crate::Category;
+
+    #[test]
+    fn bohr_into_uom_length_and_back() {
+        let length = Length::try_from(Quantity::new(1.0, "bohr")).unwrap();
+        assert_relative_eq!(
+            length.get::<length::meter>(),
+            expected::BOHR_RADIUS_IN_METRES
+        );
+        assert_relative_eq!(
+            length.get::<length::angstrom>(),
+            expected::BOHR_RADIUS_IN_ANGSTROM
+        );
+
+        let back = Quantity::from(length).convert_to("bohr").unwrap();
+        assert_relative_eq!(back.value, 1.0);
+
+        let energy = Energy::try_from(&Quantity::new(1.0, "hartree")).unwrap();
+        assert_relative_eq!(
+            energy.get::<energy::electronvolt>(),
+            27.211_386_245_988,
+            max_relative = 1e-9
+        );
+        let temperature = ThermodynamicTemperature::try_from(Quantity::new(25.0, "degC")).unwrap();
+        assert_relative_eq!(
+            temperature.get::<thermodynamic_temperature::kelvin>(),
+            298.15
+        );
+
+        assert_eq!(
+            Length::try_from(Quantity::new(1.0, "kg")),
+            Err(ConversionError::DimensionMismatch {
+                from_category: Category::Mass,
+                to_category: Category::Length,
+            })
+        );
+    }
+}
@@ -0,0 +1,41 @@
+//! This module offers dynamic viscosity unit conversion functions in double precision, i.e. f64.
+//! The base unit pascal second is written `Pa.s`.
+
+use crate::table::conversion_table;
+
+/// Poise, the CGS unit of dynamic viscosity, in pascal seconds.
+const POISE_TO_PASCAL_SECONDS: f64 = 0.1;
+const CENTIPOISE_TO_PASCAL_SECONDS: f64 = 1e-3;
+
+conversion_table!(
+    kind = "viscosity",
+    category = Viscosity,
+    a_kind = "a viscosity",
+    base = "pascal seconds",
+    /// Returns the size of the viscosity unit in pascal seconds.
+    factor = pas_per,
+    to_base = to_pas,
+    from_base = from_pas,
+    to_map = convert_2_pas_from,
+    from_map = convert_from_pas_2,
+    units = {
+        "Pa.s" => 1.0,
+        "P" => POISE_TO_PASCAL_SECONDS,
+        "cP" => CENTIPOISE_TO_PASCAL_SECONDS,
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn water_is_about_one_centipoise() {
+        assert_relative_eq!(try_convert(1.0, "cP", "Pa.s").unwrap(), 1e-3);
+        assert_relative_eq!(try_convert(1.0, "P", "cP").unwrap(), 100.0);
+        let poise = try_convert(1.002, "cP", "P").unwrap();
+        assert_relative_eq!(try_convert(poise, "P", "cP").unwrap(), 1.002);
+    }
+}